@@ -0,0 +1,410 @@
+//! Plays back the frames of a [`Clip`] in the terminal.
+//!
+//! Two rows of cells are drawn per line of text, using the upper half
+//! block with the top cell as foreground and the bottom cell as
+//! background color. Terminals without color support get shades instead.
+
+use std::{
+    fmt::Display,
+    io::{self, IsTerminal, Write},
+    str::FromStr,
+    thread,
+    time::{Duration, Instant},
+};
+
+use owo_colors::{OwoColorize, Stream::Stdout};
+
+use crate::viz::{Clip, Rgb, BLACK};
+
+pub const DEFAULT_FPS: f64 = 10.0;
+
+const HELP: &str = "space: pause, n/p: step, +/-: speed, hjkl/arrows: pan, q/esc: quit";
+
+const ESC: u8 = 0x1b;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Options {
+    pub fps: f64,
+    pub viewport: Option<Viewport>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            fps: DEFAULT_FPS,
+            viewport: None,
+        }
+    }
+}
+
+/// The part of a frame that is shown, in cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewport {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+#[derive(Debug)]
+pub struct ViewportError(String);
+
+impl Display for ViewportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ViewportError {}
+
+/// Parses `<WIDTH>x<HEIGHT>` with an optional `+<X>+<Y>` offset.
+impl FromStr for Viewport {
+    type Err = ViewportError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            ViewportError(format!(
+                "invalid viewport `{s}`, expected e.g. `80x40+10+0`"
+            ))
+        };
+        let number = |n: &str| n.parse::<usize>().map_err(|_| invalid());
+
+        let (size, offset) = s.split_once('+').unwrap_or((s, "0+0"));
+        let (width, height) = size.split_once('x').ok_or_else(invalid)?;
+        let (x, y) = offset.split_once('+').ok_or_else(invalid)?;
+
+        let viewport = Self {
+            x: number(x)?,
+            y: number(y)?,
+            width: number(width)?,
+            height: number(height)?,
+        };
+        if viewport.width == 0 || viewport.height == 0 {
+            return Err(invalid());
+        }
+        Ok(viewport)
+    }
+}
+
+/// Plays `clip` until its last frame was shown or `q` or `Esc` is pressed.
+///
+/// Keys are only read on Unix, elsewhere the clip plays once without
+/// interaction. Does nothing if stdout is not a terminal.
+pub fn play(clip: &Clip, options: &Options) -> io::Result<()> {
+    if clip.frames.is_empty() || !io::stdout().is_terminal() {
+        return Ok(());
+    }
+
+    let mut view = options.viewport.unwrap_or_else(|| {
+        let (columns, lines) = terminal_size();
+        let first = &clip.frames[0];
+        Viewport {
+            x: 0,
+            y: 0,
+            width: first.width().min(columns),
+            // two cells per line, one line is left for the status
+            height: first.height().min(lines.saturating_sub(1).max(1) * 2),
+        }
+    });
+
+    let mut stdout = io::stdout().lock();
+    let _screen = Screen::enter(&mut stdout)?;
+    let keys = keys::Keys::new();
+
+    let mut frame = 0;
+    let mut fps = options.fps;
+    let mut paused = false;
+
+    loop {
+        let last = frame + 1 == clip.frames.len();
+        draw(&mut stdout, clip, frame, view, fps, paused || last)?;
+
+        if last && !keys.enabled() {
+            break;
+        }
+
+        let deadline = Instant::now() + Duration::from_secs_f64(1.0 / fps);
+        let mut step = None;
+
+        while step.is_none() && (paused || last || Instant::now() < deadline) {
+            match keys.read() {
+                Some(b'q' | ESC) => return Ok(()),
+                Some(b' ') => paused = !paused,
+                Some(b'n' | b'.') => step = Some(1),
+                Some(b'p' | b',') => step = Some(-1),
+                Some(b'+') => fps = (fps * 2.0).min(1000.0),
+                Some(b'-') => fps = (fps / 2.0).max(0.5),
+                Some(b'h') => view.x = view.x.saturating_sub(view.width / 4),
+                Some(b'l') => view.x += view.width / 4,
+                Some(b'k') => view.y = view.y.saturating_sub(view.height / 4),
+                Some(b'j') => view.y += view.height / 4,
+                Some(_) => {}
+                None => {
+                    thread::sleep(Duration::from_millis(5));
+                    continue;
+                }
+            }
+            // redraw for changed speed, pause or viewport
+            draw(&mut stdout, clip, frame, view, fps, paused || last)?;
+        }
+
+        frame = match step {
+            Some(-1) => frame.saturating_sub(1),
+            _ => (frame + 1).min(clip.frames.len() - 1),
+        };
+    }
+
+    Ok(())
+}
+
+fn draw(
+    out: &mut impl Write,
+    clip: &Clip,
+    frame: usize,
+    view: Viewport,
+    fps: f64,
+    paused: bool,
+) -> io::Result<()> {
+    let image = &clip.frames[frame];
+    let cell = |row: usize, col: usize| {
+        (row < view.y + view.height)
+            .then(|| image.get((row, col)).copied())
+            .flatten()
+            .unwrap_or(BLACK)
+    };
+
+    let colors = supports_color();
+
+    // move the cursor home instead of clearing to avoid flicker
+    write!(out, "\x1b[H")?;
+
+    for row in (view.y..view.y + view.height).step_by(2) {
+        for col in view.x..view.x + view.width {
+            let (top, bottom) = (cell(row, col), cell(row + 1, col));
+            if colors {
+                let [r, g, b] = top;
+                let [br, bg, bb] = bottom;
+                write!(out, "{}", '▀'.truecolor(r, g, b).on_truecolor(br, bg, bb))?;
+            } else {
+                write!(out, "{}", shade(top, bottom))?;
+            }
+        }
+        write!(out, "\x1b[K\r\n")?;
+    }
+
+    let status = format!(
+        "{} {}/{} at {fps} fps{} ({HELP})",
+        clip.name,
+        frame + 1,
+        clip.frames.len(),
+        if paused { ", paused" } else { "" },
+    );
+    write!(
+        out,
+        "{}\x1b[K",
+        status.if_supports_color(Stdout, |s| s.dimmed())
+    )?;
+    out.flush()
+}
+
+/// The key for the bytes that followed an `Esc`: a bare `Esc` stays
+/// itself, arrow keys become `hjkl` and other sequences are ignored.
+#[cfg_attr(not(all(unix, feature = "viz")), allow(dead_code))]
+fn escape(sequence: &[u8]) -> Option<u8> {
+    match sequence {
+        [] => Some(ESC),
+        [b'[' | b'O', b'A'] => Some(b'k'),
+        [b'[' | b'O', b'B'] => Some(b'j'),
+        [b'[' | b'O', b'C'] => Some(b'l'),
+        [b'[' | b'O', b'D'] => Some(b'h'),
+        _ => None,
+    }
+}
+
+/// owo-colors does not expose whether stdout supports colors,
+/// so this checks whether a styled space is left unchanged.
+fn supports_color() -> bool {
+    ' '.if_supports_color(Stdout, |c| c.red()).to_string() != " "
+}
+
+/// A character with the average brightness of both cells.
+fn shade(top: Rgb, bottom: Rgb) -> char {
+    let luma = |[r, g, b]: Rgb| (u32::from(r) * 3 + u32::from(g) * 6 + u32::from(b)) / 10;
+    [' ', '░', '▒', '▓', '█'][((luma(top) + luma(bottom)) / 2 * 5 / 256) as usize]
+}
+
+/// The size of the terminal in columns and lines, as far as
+/// the environment tells, defaulting to 80x24.
+fn terminal_size() -> (usize, usize) {
+    let var = |name: &str, default: usize| {
+        std::env::var(name)
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(default)
+    };
+    (var("COLUMNS", 80), var("LINES", 24))
+}
+
+/// Switches to the alternate screen and hides the cursor until dropped.
+struct Screen;
+
+impl Screen {
+    fn enter(out: &mut impl Write) -> io::Result<Self> {
+        write!(out, "\x1b[?1049h\x1b[?25l\x1b[2J")?;
+        out.flush()?;
+        Ok(Self)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let mut out = io::stdout();
+        let _ = write!(out, "\x1b[?25h\x1b[?1049l");
+        let _ = out.flush();
+    }
+}
+
+#[cfg(all(unix, feature = "viz"))]
+mod keys {
+    use std::{
+        io::{self, Read},
+        os::fd::AsRawFd,
+        thread,
+        time::Duration,
+    };
+
+    use termios::{tcsetattr, Termios, ECHO, ICANON, TCSANOW, VMIN, VTIME};
+
+    /// Reads single key presses without waiting for a newline,
+    /// restoring the terminal settings when dropped.
+    pub(super) struct Keys {
+        original: Option<Termios>,
+    }
+
+    impl Keys {
+        pub(super) fn new() -> Self {
+            let fd = io::stdin().as_raw_fd();
+            let original = Termios::from_fd(fd).ok().filter(|original| {
+                let mut raw = *original;
+                raw.c_lflag &= !(ICANON | ECHO);
+                raw.c_cc[VMIN] = 0;
+                raw.c_cc[VTIME] = 0;
+                tcsetattr(fd, TCSANOW, &raw).is_ok()
+            });
+            Self { original }
+        }
+
+        pub(super) fn enabled(&self) -> bool {
+            self.original.is_some()
+        }
+
+        /// The next pressed key, if any, without blocking.
+        pub(super) fn read(&self) -> Option<u8> {
+            let key = self.byte()?;
+            if key != super::ESC {
+                return Some(key);
+            }
+            // the rest of an escape sequence follows right away,
+            // while a bare `Esc` stays alone
+            thread::sleep(Duration::from_millis(10));
+            let sequence = std::iter::from_fn(|| self.byte()).collect::<Vec<_>>();
+            super::escape(&sequence)
+        }
+
+        fn byte(&self) -> Option<u8> {
+            self.original.as_ref()?;
+            let mut key = [0];
+            match io::stdin().read(&mut key) {
+                Ok(1) => Some(key[0]),
+                _ => None,
+            }
+        }
+    }
+
+    impl Drop for Keys {
+        fn drop(&mut self) {
+            if let Some(original) = &self.original {
+                let _ = tcsetattr(io::stdin().as_raw_fd(), TCSANOW, original);
+            }
+        }
+    }
+}
+
+#[cfg(not(all(unix, feature = "viz")))]
+mod keys {
+    pub(super) struct Keys;
+
+    impl Keys {
+        pub(super) fn new() -> Self {
+            Self
+        }
+
+        pub(super) fn enabled(&self) -> bool {
+            false
+        }
+
+        pub(super) fn read(&self) -> Option<u8> {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_viewport() {
+        assert_eq!(
+            "80x40".parse::<Viewport>().unwrap(),
+            Viewport {
+                x: 0,
+                y: 0,
+                width: 80,
+                height: 40
+            }
+        );
+        assert_eq!(
+            "10x20+30+5".parse::<Viewport>().unwrap(),
+            Viewport {
+                x: 30,
+                y: 5,
+                width: 10,
+                height: 20
+            }
+        );
+        assert!("80".parse::<Viewport>().is_err());
+        assert!("0x10".parse::<Viewport>().is_err());
+        assert!("10x10+5".parse::<Viewport>().is_err());
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape(b""), Some(ESC));
+        assert_eq!(escape(b"[A"), Some(b'k'));
+        assert_eq!(escape(b"OD"), Some(b'h'));
+        assert_eq!(escape(b"[5~"), None);
+    }
+
+    #[test]
+    fn test_draw() {
+        let clip = Clip {
+            name: "test".to_string(),
+            frames: vec![crate::grid::Grid::filled(3, 3, BLACK)],
+        };
+        let view = Viewport {
+            x: 1,
+            y: 0,
+            width: 4,
+            height: 3,
+        };
+
+        let mut out = Vec::new();
+        draw(&mut out, &clip, 0, view, DEFAULT_FPS, true).unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        // 3 rows are drawn as 2 lines of 4 cells, followed by the status
+        assert_eq!(out.matches("\r\n").count(), 2);
+        assert!(out.contains("test 1/1 at 10 fps, paused"));
+    }
+}
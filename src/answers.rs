@@ -0,0 +1,209 @@
+use fxhash::FxHashMap;
+use std::{fmt::Display, path::Path};
+use toml::{Table, Value};
+
+#[derive(Debug)]
+pub struct AnswersError(String);
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect { expected: String },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect { .. } => "incorrect",
+            Verdict::Unknown => "unknown",
+        })
+    }
+}
+
+/// The known answers of a single year, read from a manifest like
+///
+/// ```toml
+/// [day1]
+/// part1 = 71023
+/// part2 = "LVMRWSSPZ"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    days: FxHashMap<u8, [Option<String>; 2]>,
+}
+
+impl Answers {
+    pub fn parse(s: &str) -> Result<Self, AnswersError> {
+        let table = s
+            .parse::<Table>()
+            .map_err(|e| AnswersError(e.message().to_string()))?;
+
+        let mut days = FxHashMap::default();
+
+        for (key, value) in table {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| {
+                    AnswersError(format!("invalid key `{key}`, expected e.g. `day1`"))
+                })?;
+
+            let Value::Table(parts) = value else {
+                return Err(AnswersError(format!("`{key}` is not a table")));
+            };
+
+            let mut answers = [None, None];
+
+            for (part, value) in parts {
+                let idx = match part.as_str() {
+                    "part1" => 0,
+                    "part2" => 1,
+                    _ => {
+                        return Err(AnswersError(format!(
+                            "invalid key `{key}.{part}`, expected `part1` or `part2`"
+                        )))
+                    }
+                };
+                answers[idx] = Some(match value {
+                    Value::Integer(n) => n.to_string(),
+                    Value::String(s) => s,
+                    _ => {
+                        return Err(AnswersError(format!(
+                            "`{key}.{part}` must be an integer or a string"
+                        )))
+                    }
+                });
+            }
+
+            days.insert(day, answers);
+        }
+
+        Ok(Self { days })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.days
+            .get(&day)
+            .and_then(|answers| answers[usize::from(part) - 1].as_deref())
+    }
+
+    pub fn check<T: Display + ?Sized>(&self, day: u8, part: u8, answer: &T) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected.trim() == answer.to_string().trim() => Verdict::Correct,
+            Some(expected) => Verdict::Incorrect {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+/// Extracts the day from a puzzle file like `input/day7.txt`.
+pub fn day_of(file: &str) -> Option<u8> {
+    Path::new(file)
+        .file_stem()?
+        .to_str()?
+        .strip_prefix("day")?
+        .parse()
+        .ok()
+}
+
+/// Asserts that both answers for the puzzle `file` match the manifest,
+/// used by the tests that `register!` generates for every day.
+#[track_caller]
+pub fn assert_answers<A, B>(answers: &str, file: &str, part1: &A, part2: &B)
+where
+    A: Display + ?Sized,
+    B: Display + ?Sized,
+{
+    let answers = Answers::parse(answers).unwrap_or_else(|e| panic!("invalid answers: {e}"));
+    let day = day_of(file).unwrap_or_else(|| panic!("cannot tell the day of `{file}`"));
+
+    for (part, answer) in [(1, part1.to_string()), (2, part2.to_string())] {
+        match answers.check(day, part, &answer) {
+            Verdict::Correct => {}
+            Verdict::Incorrect { expected } => {
+                assert_eq!(answer.trim(), expected.trim(), "part {part} of day {day}")
+            }
+            Verdict::Unknown => panic!("no answer for part {part} of day {day}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[day1]
+part1 = 71023
+part2 = "LVMRWSSPZ"
+
+[day10]
+part2 = '''
+#..#
+####'''
+"#;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.get(1, 1), Some("71023"));
+        assert_eq!(answers.get(1, 2), Some("LVMRWSSPZ"));
+        assert_eq!(answers.get(10, 1), None);
+        assert_eq!(answers.get(10, 2), Some("#..#\n####"));
+        assert_eq!(answers.get(2, 1), None);
+
+        assert!(Answers::parse("").unwrap().get(1, 1).is_none());
+        assert!(Answers::parse("[one]\npart1 = 1").is_err());
+        assert!(Answers::parse("[day1]\npart3 = 1").is_err());
+        assert!(Answers::parse("[day1]\npart1 = 1.5").is_err());
+        assert!(Answers::parse("day1 = 1").is_err());
+        assert!(Answers::parse("[day1").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.check(1, 1, &71023), Verdict::Correct);
+        assert_eq!(
+            answers.check(1, 1, &42),
+            Verdict::Incorrect {
+                expected: "71023".to_string()
+            }
+        );
+        assert_eq!(answers.check(10, 2, "\n#..#\n####\n"), Verdict::Correct);
+        assert_eq!(answers.check(10, 1, &0), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_assert_answers() {
+        assert_eq!(day_of("input/day17.txt"), Some(17));
+        assert_eq!(day_of("day3.txt"), Some(3));
+        assert_eq!(day_of("input/example.txt"), None);
+
+        assert_answers(ANSWERS, "input/day1.txt", &71023, "LVMRWSSPZ");
+    }
+
+    #[test]
+    #[should_panic(expected = "part 1 of day 1")]
+    fn test_assert_answers_incorrect() {
+        assert_answers(ANSWERS, "input/day1.txt", &42, "LVMRWSSPZ");
+    }
+
+    #[test]
+    #[should_panic(expected = "no answer for part 1 of day 10")]
+    fn test_assert_answers_unknown() {
+        assert_answers(ANSWERS, "input/day10.txt", &0, "#..#\n####");
+    }
+}
@@ -0,0 +1,276 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Write},
+    time::Duration,
+};
+use toml::{Table, Value};
+
+use crate::report::Record;
+
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+#[derive(Debug)]
+pub struct BaselineError(String);
+
+impl Display for BaselineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for BaselineError {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Option<Duration>,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+impl Timings {
+    fn of(record: &Record) -> Option<Self> {
+        record.error.is_none().then(|| Self {
+            parse: record.parse_time,
+            part1: record.part1.as_ref().map(|answer| answer.time),
+            part2: record.part2.as_ref().map(|answer| answer.time),
+        })
+    }
+
+    fn get(&self) -> [Option<Duration>; 3] {
+        [self.parse, self.part1, self.part2]
+    }
+
+    /// Sums the timings that were measured in both runs,
+    /// so that e.g. `--part 1` can be compared to a full run.
+    fn common_total(&self, other: &Self) -> Option<(Duration, Duration)> {
+        self.get()
+            .into_iter()
+            .zip(other.get())
+            .filter_map(|(a, b)| a.zip(b))
+            .reduce(|(a, b), (c, d)| (a + c, b + d))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Delta {
+    pub year: u16,
+    pub day: u8,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Delta {
+    /// Relative change in percent, positive if the day got slower.
+    pub fn percent(&self) -> f64 {
+        if self.before.is_zero() {
+            return 0.0;
+        }
+        (self.after.as_secs_f64() - self.before.as_secs_f64()) / self.before.as_secs_f64() * 100.0
+    }
+
+    pub fn regressed(&self, threshold: f64) -> bool {
+        self.percent() > threshold
+    }
+}
+
+impl Display for Delta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let percent = self.percent();
+        let change = if percent > 0.0 { "slower" } else { "faster" };
+        write!(
+            f,
+            "{:.1}% {change} (was {})",
+            percent.abs(),
+            humantime::format_duration(self.before)
+        )
+    }
+}
+
+/// Timings of previous runs, stored as
+///
+/// ```toml
+/// [2022.day1]
+/// parse_ns = 71623
+/// part1_ns = 176
+/// part2_ns = 474
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    days: BTreeMap<(u16, u8), Timings>,
+}
+
+impl Baseline {
+    pub fn parse(s: &str) -> Result<Self, BaselineError> {
+        let table = s
+            .parse::<Table>()
+            .map_err(|e| BaselineError(e.message().to_string()))?;
+
+        let mut days = BTreeMap::new();
+
+        for (year_key, value) in table {
+            let year = year_key
+                .parse::<u16>()
+                .map_err(|_| BaselineError(format!("invalid year `{year_key}`")))?;
+            let Value::Table(table) = value else {
+                return Err(BaselineError(format!("`{year_key}` is not a table")));
+            };
+
+            for (day_key, value) in table {
+                let key = format!("{year_key}.{day_key}");
+                let day = day_key
+                    .strip_prefix("day")
+                    .and_then(|day| day.parse::<u8>().ok())
+                    .ok_or_else(|| BaselineError(format!("invalid day `{key}`")))?;
+                let Value::Table(table) = value else {
+                    return Err(BaselineError(format!("`{key}` is not a table")));
+                };
+
+                let mut timings = Timings::default();
+
+                for (name, value) in table {
+                    let time = match value {
+                        Value::Integer(ns) if ns >= 0 => Duration::from_nanos(ns.unsigned_abs()),
+                        _ => {
+                            return Err(BaselineError(format!(
+                                "`{key}.{name}` must be a number of nanoseconds"
+                            )))
+                        }
+                    };
+                    match name.as_str() {
+                        "parse_ns" => timings.parse = Some(time),
+                        "part1_ns" => timings.part1 = Some(time),
+                        "part2_ns" => timings.part2 = Some(time),
+                        _ => return Err(BaselineError(format!("unknown timing `{key}.{name}`"))),
+                    }
+                }
+
+                days.insert((year, day), timings);
+            }
+        }
+
+        Ok(Self { days })
+    }
+
+    /// Replaces the timings of all successfully solved days in `records`.
+    pub fn update(&mut self, records: &[Record]) {
+        for record in records {
+            if let Some(timings) = Timings::of(record) {
+                self.days.insert((record.year, record.day), timings);
+            }
+        }
+    }
+
+    pub fn compare(&self, records: &[Record]) -> Vec<Delta> {
+        records
+            .iter()
+            .filter_map(|record| {
+                let after = Timings::of(record)?;
+                let before = self.days.get(&(record.year, record.day))?;
+                let (before, after) = before.common_total(&after)?;
+
+                Some(Delta {
+                    year: record.year,
+                    day: record.day,
+                    before,
+                    after,
+                })
+            })
+            .collect()
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut sections = Vec::with_capacity(self.days.len());
+
+        for ((year, day), timings) in &self.days {
+            let mut section = format!("[{year}.day{day}]\n");
+            let names = ["parse_ns", "part1_ns", "part2_ns"];
+            for (name, time) in names.into_iter().zip(timings.get()) {
+                if let Some(time) = time {
+                    writeln!(section, "{name} = {}", time.as_nanos())?;
+                }
+            }
+            sections.push(section);
+        }
+
+        f.write_str(&sections.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::Verdict, report::Answer};
+
+    fn record(year: u16, day: u8, times: [u64; 3]) -> Record {
+        let answer = |ns| Some(Answer::new(&0, Duration::from_nanos(ns), Verdict::Unknown));
+        Record {
+            parse_time: Some(Duration::from_nanos(times[0])),
+            part1: answer(times[1]),
+            part2: answer(times[2]),
+            ..Record::new(year, day)
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut baseline = Baseline::default();
+        baseline.update(&[
+            record(2022, 10, [100, 20, 30]),
+            record(2021, 1, [1, 2, 3]),
+            Record {
+                error: Some("failed".to_string()),
+                ..Record::new(2021, 2)
+            },
+        ]);
+
+        let toml = baseline.to_string();
+        assert_eq!(
+            toml,
+            "\
+[2021.day1]
+parse_ns = 1
+part1_ns = 2
+part2_ns = 3
+
+[2022.day10]
+parse_ns = 100
+part1_ns = 20
+part2_ns = 30
+"
+        );
+        assert_eq!(Baseline::parse(&toml).unwrap(), baseline);
+
+        assert!(Baseline::parse("[twenty.day1]").is_err());
+        assert!(Baseline::parse("[2022.one]").is_err());
+        assert!(Baseline::parse("[2022.day1]\nparse_ns = -1").is_err());
+        assert!(Baseline::parse("[2022.day1]\nparse = 1").is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut baseline = Baseline::default();
+        baseline.update(&[record(2022, 1, [100, 50, 50]), record(2022, 2, [10, 10, 0])]);
+
+        let mut part1_only = record(2022, 2, [10, 5, 0]);
+        part1_only.part2 = None;
+
+        let deltas = baseline.compare(&[
+            record(2022, 1, [100, 100, 50]),
+            part1_only,
+            record(2022, 3, [1, 1, 1]),
+        ]);
+
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[0].percent(), 25.0);
+        assert!(deltas[0].regressed(DEFAULT_THRESHOLD));
+        assert!(!deltas[0].regressed(30.0));
+        assert_eq!(deltas[0].to_string(), "25.0% slower (was 200ns)");
+
+        assert_eq!(deltas[1].before, Duration::from_nanos(20));
+        assert_eq!(deltas[1].after, Duration::from_nanos(15));
+        assert!(!deltas[1].regressed(DEFAULT_THRESHOLD));
+        assert_eq!(deltas[1].to_string(), "25.0% faster (was 20ns)");
+    }
+}
@@ -0,0 +1,82 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::MedianExt;
+
+/// Summary statistics over repeated timings of the same computation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "cannot compute stats without samples");
+
+        let runs = samples.len();
+        let min = samples.iter().copied().min().unwrap_or_default();
+        let mean = samples.iter().sum::<Duration>() / runs as u32;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / runs as f64;
+        let stddev = Duration::from_secs_f64(variance.sqrt());
+        let median = samples.median();
+
+        Self {
+            runs,
+            min,
+            median,
+            mean,
+            stddev,
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use humantime::format_duration;
+
+        write!(
+            f,
+            "min {}, median {}, mean {} ± {}",
+            format_duration(self.min),
+            format_duration(self.median),
+            format_duration(self.mean),
+            format_duration(self.stddev)
+        )
+    }
+}
+
+/// Number of untimed runs before sampling.
+pub fn warmup(runs: usize) -> usize {
+    (runs / 10).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 2, 9, 5, 4, 5, 7, 4].map(Duration::from_micros).to_vec();
+        let stats = Stats::new(samples);
+
+        assert_eq!(stats.runs, 8);
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(stats.median, Duration::from_micros(5));
+        assert_eq!(stats.mean, Duration::from_micros(5));
+        assert_eq!(stats.stddev, Duration::from_micros(2));
+        assert_eq!(stats.to_string(), "min 2us, median 5us, mean 5us ± 2us");
+    }
+
+    #[test]
+    fn test_warmup() {
+        assert_eq!(warmup(1), 1);
+        assert_eq!(warmup(100), 10);
+    }
+}
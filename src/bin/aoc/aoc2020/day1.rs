@@ -4,9 +4,17 @@ type Output = usize;
 register!(
     "input/day1.txt";
     (input: input!(parse Input)) -> Output {
-        part1(&input);
-        part2(&input);
+        part1(input);
+        part2(input);
     }
+    example r#"
+            1721
+            979
+            366
+            299
+            675
+            1456
+            "# => part1 514579, part2 241861950;
 );
 
 fn part1(items: &[Input]) -> Output {
@@ -44,51 +52,3 @@ fn part2(mut items: &[Input]) -> Output {
 
     unreachable!()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-            1721
-            979
-            366
-            299
-            675
-            1456
-            "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 514579);
-        assert_eq!(res2, 241861950);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 870331);
-        assert_eq!(res2, 283025088);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
-}
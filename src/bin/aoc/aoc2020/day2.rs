@@ -1,6 +1,6 @@
 use std::{num::ParseIntError, str::FromStr};
 
-use aoc::{lines, PuzzleInput};
+use aoc::{lines, InputError, PuzzleInput};
 
 type Input = Passwords;
 type Output = usize;
@@ -8,9 +8,14 @@ type Output = usize;
 register!(
     "input/day2.txt";
     (input: input!(verbatim Input)) -> Output {
-        part1(input);
-        part2(input);
+        part1(*input);
+        part2(*input);
     }
+    example r#"
+        1-3 a: abcde
+        1-3 b: cdefg
+        2-9 c: ccccccccc
+        "# => part1 2, part2 1;
 );
 
 fn part1(items: (usize, usize)) -> Output {
@@ -26,8 +31,8 @@ pub struct Passwords;
 impl PuzzleInput for Passwords {
     type Out = (Output, Output);
 
-    fn from_input(input: &str) -> Self::Out {
-        lines(input).map(|line| line.parse::<Line>().unwrap()).fold(
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
+        Ok(lines(input).map(|line| line.parse::<Line>().unwrap()).fold(
             (0, 0),
             |(in_range_cnt, at_index_cnt), Line { in_range, at_index }| {
                 (
@@ -35,7 +40,7 @@ impl PuzzleInput for Passwords {
                     at_index_cnt + usize::from(at_index),
                 )
             },
-        )
+        ))
     }
 }
 
@@ -62,48 +67,3 @@ impl FromStr for Line {
         Ok(Self { in_range, at_index })
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        1-3 a: abcde
-        1-3 b: cdefg
-        2-9 c: ccccccccc
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 2);
-        assert_eq!(res2, 1);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 424);
-        assert_eq!(res2, 747);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(input));
-    }
-}
@@ -0,0 +1,46 @@
+use aoc::grid::Grid;
+
+type Input = Grid<u8>;
+type Output = usize;
+
+register!(
+    "input/day3.txt";
+    (input: input!(grid u8)) -> Output {
+        part1(input);
+        part2(input);
+    }
+    example r#"
+        ..##.......
+        #...#...#..
+        .#....#..#.
+        ..#.#...#.#
+        .#...##..#.
+        ..#.##.....
+        .#.#.#....#
+        .#........#
+        #.##...#...
+        #...##....#
+        .#..#...#.#
+        "# => part1 7, part2 336;
+);
+
+fn part1(field: &Input) -> Output {
+    trees(field, 3, 1)
+}
+
+fn part2(field: &Input) -> Output {
+    [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+        .into_iter()
+        .map(|(right, down)| trees(field, right, down))
+        .product()
+}
+
+/// Counts the trees hit when going `right` and `down` from the top-left.
+fn trees(field: &Input, right: usize, down: usize) -> Output {
+    (0..field.height())
+        .step_by(down)
+        .enumerate()
+        .skip(1)
+        .filter(|&(step, row)| field.row(row)[(step * right) % field.width()] == b'#')
+        .count()
+}
@@ -4,9 +4,15 @@ type Output = usize;
 register!(
     "input/day5.txt";
     (input: input!(Input)) -> Output {
-        part1(&input);
-        part2(&input);
+        part1(input);
+        part2(input);
     }
+    example r#"
+        FBFBBFFRLR        
+        BFFFBBFRRR
+        FFFBBBFRRR
+        BBFFBBFRLL
+        "# => part1 820;
 );
 
 fn part1(items: &[Input]) -> Output {
@@ -44,48 +50,3 @@ fn fold(pass: &str, sep: u8, init: (usize, usize)) -> usize {
         _ => *high,
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        FBFBBFFRLR        
-        BFFFBBFRRR
-        FFFBBBFRRR
-        BBFFBBFRLL
-        "#;
-        let (res1, _) = Solver::run_on(input);
-        assert_eq!(res1, 820);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 885);
-        assert_eq!(res2, 623);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
-}
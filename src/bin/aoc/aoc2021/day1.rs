@@ -0,0 +1,32 @@
+type Input = u64;
+type Output = usize;
+
+register!(
+    "input/day1.txt";
+    (input: input!(parse Input)) -> Output {
+        part1(input);
+        part2(input);
+    }
+    example r#"199
+                       200
+                       208
+                       210
+                       200
+                       207
+                       240
+                       269
+                       260
+                       263"# => part1 7, part2 5;
+);
+
+fn part1(measurements: &[Input]) -> Output {
+    measurements.array_windows().filter(|[a, b]| a < b).count()
+}
+
+fn part2(measurements: &[Input]) -> Output {
+    measurements
+        .array_windows()
+        // (a + b + c) < (b + c + d) <=> a < d
+        .filter(|[a, _, _, d]| a < d)
+        .count()
+}
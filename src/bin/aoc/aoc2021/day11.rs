@@ -0,0 +1,126 @@
+use aoc::{
+    grid::{Grid, Pos, DELTAS8},
+    viz::{Recorder, Rgb},
+    InputError, PuzzleInput,
+};
+use derive_more::{Deref, DerefMut};
+
+type Input = Field;
+type Output = usize;
+
+register!(
+    "input/day11.txt";
+    (input: input!(verbatim Input)) -> Output {
+        part1(input);
+        part2(input);
+    }
+    example r#"
+        5483143223
+        2745854711
+        5264556173
+        6141336146
+        6357385478
+        4167524645
+        2176841721
+        6882881134
+        4846848554
+        5283751526
+        "# => part1 1656, part2 195;
+);
+
+fn part1(field: &Input) -> Output {
+    let mut field = field.clone();
+    let mut flashes = 0;
+
+    for _ in 0..100 {
+        let mut queue = field.increment();
+
+        while let Some(next) = queue.pop() {
+            flashes += 1;
+            field.flash(next, &mut queue);
+        }
+    }
+
+    flashes
+}
+
+fn part2(field: &Input) -> Output {
+    let mut field = field.clone();
+    let mut recorder = Recorder::new("2021/day11").scale(16);
+    let mut step = 1;
+
+    loop {
+        let mut flashes = 0;
+        let mut queue = field.increment();
+
+        while let Some(next) = queue.pop() {
+            flashes += 1;
+            field.flash(next, &mut queue);
+        }
+
+        recorder.frame(field.width(), field.height(), |pos| field.color(pos));
+
+        if flashes == field.cells().len() {
+            return step;
+        }
+
+        step += 1;
+    }
+}
+
+#[derive(Deref, DerefMut, Clone)]
+pub struct Field(Grid<u8>);
+
+impl Field {
+    /// Flashing octopuses are white, all others get brighter with their energy.
+    fn color(&self, pos: Pos) -> Rgb {
+        match self[pos] {
+            0 => [255, 255, 255],
+            energy => [0, 0, energy * 20],
+        }
+    }
+
+    fn increment(&mut self) -> Vec<Pos> {
+        let mut queue = vec![];
+
+        for pos in self.positions() {
+            self[pos] += 1;
+
+            if self[pos] > 9 {
+                queue.push(pos);
+            }
+        }
+
+        queue
+    }
+
+    fn flash(&mut self, pos: Pos, queue: &mut Vec<Pos>) {
+        self[pos] = 0;
+
+        for delta in DELTAS8 {
+            let Some(next) = self.offset(pos, delta) else {
+                continue;
+            };
+            let n = &mut self[next];
+            if *n > 0 {
+                *n += 1;
+
+                if *n > 9 && !queue.contains(&next) {
+                    queue.push(next);
+                }
+            }
+        }
+    }
+}
+
+impl PuzzleInput for Field {
+    type Out = Self;
+
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
+        Grid::parse_with(input, |b| match b {
+            b'0'..=b'9' => Ok(b - b'0'),
+            _ => Err(char::from(b)),
+        })
+        .map(Self)
+    }
+}
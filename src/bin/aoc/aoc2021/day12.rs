@@ -1,4 +1,4 @@
-use aoc::{lines, PuzzleInput};
+use aoc::{lines, InputError, PuzzleInput};
 use graph::prelude::*;
 use indexmap::IndexSet;
 
@@ -8,9 +8,30 @@ type Output = u32;
 register!(
     "input/day12.txt";
     (input: input!(verbatim Input)) -> Output {
-        part1(&input);
-        part2(&input);
+        part1(input);
+        part2(input);
     }
+    example r#"
+        start-A
+        start-b
+        A-c
+        A-b
+        b-d
+        A-end
+        b-end
+        "# => part1 10, part2 36;
+    example r#"
+        dc-end
+        HN-start
+        start-kj
+        dc-start
+        dc-HN
+        LN-dc
+        HN-end
+        kj-sa
+        kj-HN
+        kj-dc
+        "# => part1 19, part2 103;
 );
 
 fn part1(cave_system: &Input) -> Output {
@@ -88,7 +109,7 @@ impl CaveSystem {
 impl PuzzleInput for CaveSystem {
     type Out = Self;
 
-    fn from_input(input: &str) -> Self::Out {
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
         let mut ids = IndexSet::new();
         let mut edges = Vec::new();
 
@@ -118,74 +139,6 @@ impl PuzzleInput for CaveSystem {
             .node_values(node_values)
             .build();
 
-        Self { g, ids }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex_1() {
-        let input = r#"
-        start-A
-        start-b
-        A-c
-        A-b
-        b-d
-        A-end
-        b-end
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 10);
-        assert_eq!(res2, 36);
-    }
-
-    #[test]
-    fn test_ex_2() {
-        let input = r#"
-        dc-end
-        HN-start
-        start-kj
-        dc-start
-        dc-HN
-        LN-dc
-        HN-end
-        kj-sa
-        kj-HN
-        kj-dc
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 19);
-        assert_eq!(res2, 103);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 5104);
-        assert_eq!(res2, 149220);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
+        Ok(Self { g, ids })
     }
 }
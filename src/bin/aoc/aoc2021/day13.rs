@@ -0,0 +1,87 @@
+use std::{collections::HashSet, str::FromStr};
+
+use aoc::{geom::Point2, ocr, parse::ParseError, scan};
+
+type Input = (Vec<Point2>, Vec<Fold>);
+type Output1 = usize;
+type Output2 = String;
+
+register!(
+    "input/day13.txt";
+    (input: input!(sections (input!(parse Point2), input!(parse Fold)))) -> (Output1, Output2) {
+        part1(input);
+        part2(input);
+    }
+    example r#"
+        6,10
+        0,14
+        9,10
+        0,3
+        10,4
+        4,11
+        6,0
+        6,12
+        4,1
+        0,13
+        10,12
+        3,4
+        3,0
+        8,4
+        1,10
+        2,14
+        8,10
+        9,0
+
+        fold along y=7
+        fold along x=5
+        "# => part1 17, part2 "#####\n#...#\n#...#\n#...#\n#####";
+);
+
+fn part1((dots, folds): &Input) -> Output1 {
+    fold(dots, folds[0]).len()
+}
+
+fn part2((dots, folds): &Input) -> Output2 {
+    let dots = folds.iter().fold(dots.clone(), |d, f| fold(&d, *f));
+
+    ocr::read_points(dots.iter().copied()).unwrap_or_else(|_| render(&dots))
+}
+
+fn render(dots: &[Point2]) -> String {
+    let (_, max) = Point2::bounds(dots.iter().copied()).unwrap();
+
+    let row = vec!['.'; max.x() as usize + 1];
+    let mut m = vec![row; max.y() as usize + 1];
+
+    dots.iter()
+        .for_each(|d| m[d.y() as usize][d.x() as usize] = '#');
+
+    m.iter()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn fold(dots: &[Point2], Fold(axis, line): Fold) -> Vec<Point2> {
+    dots.iter()
+        .map(|&d| match axis {
+            'x' if d.x() > line => Point2::new([2 * line - d.x(), d.y()]),
+            'y' if d.y() > line => Point2::new([d.x(), 2 * line - d.y()]),
+            _ => d,
+        })
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>()
+}
+
+#[derive(Clone, Copy)]
+pub struct Fold(char, i64);
+
+impl FromStr for Fold {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (axis, line) = scan!(s, "fold along {}={}" => char, i64)?;
+        Ok(Self(axis, line))
+    }
+}
@@ -0,0 +1,98 @@
+use fxhash::{FxBuildHasher, FxHashMap};
+
+use aoc::{numbered_lines, scan, InputError, PuzzleInput};
+
+type Input = (String, FxHashMap<(char, char), char>);
+type Output = usize;
+
+register!(
+    "input/day14.txt";
+    (input: input!(sections (input!(first input!(String)), Rules))) -> Output {
+        part1(input);
+        part2(input);
+    }
+    example r#"
+        NNCB
+
+        CH -> B
+        HH -> N
+        CB -> H
+        NH -> C
+        HB -> C
+        HC -> B
+        HN -> C
+        NN -> C
+        BH -> H
+        NC -> B
+        NB -> B
+        BN -> B
+        BB -> N
+        BC -> B
+        CC -> N
+        CN -> C
+        "# => part1 1588, part2 2188189693529;
+);
+
+fn part1((template, rules): &Input) -> Output {
+    iterate(template, rules, 10)
+}
+
+fn part2((template, rules): &Input) -> Output {
+    iterate(template, rules, 40)
+}
+
+fn iterate(template: &str, rules: &FxHashMap<(char, char), char>, steps: u32) -> usize {
+    let template = template.chars().collect::<Vec<_>>();
+    let mut counts = template.array_windows().fold(
+        FxHashMap::with_capacity_and_hasher(template.len(), FxBuildHasher::default()),
+        |mut counts, [left, right]| {
+            *counts.entry((*left, *right)).or_insert(0) += 1;
+            counts
+        },
+    );
+
+    let mut char_counts = template.iter().fold(
+        FxHashMap::with_capacity_and_hasher(template.len(), FxBuildHasher::default()),
+        |mut counts, n| {
+            *counts.entry(*n).or_insert(0) += 1;
+            counts
+        },
+    );
+
+    let mut next = FxHashMap::with_capacity_and_hasher(template.len(), FxBuildHasher::default());
+
+    for _ in 0..steps {
+        next.clear();
+        for (t @ (left, right), count) in &counts {
+            let mid = rules.get(t).unwrap();
+
+            *next.entry((*left, *mid)).or_insert(0) += count;
+            *next.entry((*mid, *right)).or_insert(0) += count;
+
+            *char_counts.entry(*mid).or_insert(0) += count;
+        }
+
+        std::mem::swap(&mut counts, &mut next);
+    }
+
+    char_counts.values().max().unwrap() - char_counts.values().min().unwrap()
+}
+
+pub struct Rules;
+
+impl PuzzleInput for Rules {
+    type Out = FxHashMap<(char, char), char>;
+
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
+        numbered_lines(input)
+            .map(|(n, line)| {
+                let (pair, insertion) = scan!(line, "{} -> {}" => String, char)
+                    .map_err(|e| InputError::line(n, line, e))?;
+                match pair.chars().collect::<Vec<_>>()[..] {
+                    [left, right] => Ok(((left, right), insertion)),
+                    _ => Err(InputError::line(n, line, "expected a pair of elements")),
+                }
+            })
+            .collect()
+    }
+}
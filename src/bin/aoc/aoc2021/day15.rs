@@ -0,0 +1,57 @@
+use aoc::{
+    grid::{Grid, Pos},
+    search::dijkstra,
+};
+
+type Input = Grid<u8>;
+type Output = u32;
+
+register!(
+    "input/day15.txt";
+    (input: input!(grid u8)) -> Output {
+        part1(input);
+        part2(input);
+    }
+    example r#"
+        1163751742
+        1381373672
+        2136511328
+        3694931569
+        7463417111
+        1319128137
+        1359912421
+        3125421639
+        1293138521
+        2311944581
+        "# => part1 40, part2 315;
+);
+
+fn part1(cave: &Input) -> Output {
+    lowest_risk(&scale(cave, 1))
+}
+
+fn part2(cave: &Input) -> Output {
+    lowest_risk(&scale(cave, 5))
+}
+
+fn lowest_risk(cave: &Grid<u32>) -> Output {
+    let end = (cave.height() - 1, cave.width() - 1);
+    let successors = |&pos: &Pos| cave.neighbors4(pos).map(|next| (next, cave[next]));
+
+    dijkstra([(0, 0)], successors, |&pos| pos == end).map_or(u32::MAX, |path| path.cost)
+}
+
+/// Tiles the cave `factor` times in both directions,
+/// increasing the risk by one for every tile step.
+fn scale(cave: &Input, factor: usize) -> Grid<u32> {
+    let (width, height) = (cave.width(), cave.height());
+    let mut scaled = Grid::filled(width * factor, height * factor, 0);
+
+    for (row, col) in scaled.positions() {
+        let risk = u32::from(cave[(row % height, col % width)] - b'0');
+        let step = (row / height + col / width) as u32;
+        scaled[(row, col)] = (risk - 1 + step) % 9 + 1;
+    }
+
+    scaled
+}
@@ -8,9 +8,21 @@ type Output = usize;
 register!(
     "input/day16.txt";
     (input: input!(first input!(parse Input))) -> Output {
-        part1(&input);
-        part2(&input);
+        part1(input);
+        part2(input);
     }
+    example "8A004A801A8002F478" => part1 16;
+    example "620080001611562C8802118E34" => part1 12;
+    example "C0015000016115A2E0802F182340" => part1 23;
+    example "A0016C880162017C3686B18A3D4780" => part1 31;
+    example "C200B40A82" => part2 3;
+    example "04005AC33890" => part2 54;
+    example "880086C3E88112" => part2 7;
+    example "CE00C43D881120" => part2 9;
+    example "D8005AC2A8F0" => part2 1;
+    example "F600BC2D8F" => part2 0;
+    example "9C005AC2F8F0" => part2 0;
+    example "9C0141080250320F1802104A08" => part2 1;
 );
 
 fn part1(line: &str) -> Output {
@@ -131,78 +143,3 @@ impl FromStr for Line {
         })))
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex_part1() {
-        let (res1, _) = Solver::run_on("8A004A801A8002F478");
-        assert_eq!(res1, 16);
-
-        let (res1, _) = Solver::run_on("620080001611562C8802118E34");
-        assert_eq!(res1, 12);
-
-        let (res1, _) = Solver::run_on("C0015000016115A2E0802F182340");
-        assert_eq!(res1, 23);
-
-        let (res1, _) = Solver::run_on("A0016C880162017C3686B18A3D4780");
-        assert_eq!(res1, 31);
-    }
-
-    #[test]
-    fn test_ex_part2() {
-        let (_, res2) = Solver::run_on("C200B40A82");
-        assert_eq!(res2, 3);
-
-        let (_, res2) = Solver::run_on("04005AC33890");
-        assert_eq!(res2, 54);
-
-        let (_, res2) = Solver::run_on("880086C3E88112");
-        assert_eq!(res2, 7);
-
-        let (_, res2) = Solver::run_on("CE00C43D881120");
-        assert_eq!(res2, 9);
-
-        let (_, res2) = Solver::run_on("D8005AC2A8F0");
-        assert_eq!(res2, 1);
-
-        let (_, res2) = Solver::run_on("F600BC2D8F");
-        assert_eq!(res2, 0);
-
-        let (_, res2) = Solver::run_on("9C005AC2F8F0");
-        assert_eq!(res2, 0);
-
-        let (_, res2) = Solver::run_on("9C0141080250320F1802104A08");
-        assert_eq!(res2, 1);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 979);
-        assert_eq!(res2, 277110354175);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
-}
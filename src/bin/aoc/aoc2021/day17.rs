@@ -0,0 +1,88 @@
+use std::str::FromStr;
+
+use aoc::parse::ParseError;
+
+type Input = Target;
+type Output = i32;
+
+register!(
+    "input/day17.txt";
+    (input: input!(first input!(parse Input))) -> Output {
+        part1(input);
+        part2(input);
+    }
+    example r#"target area: x=20..30, y=-10..-5"# => part1 45, part2 112;
+);
+
+fn part1(target: &Input) -> Output {
+    simulate(target).0
+}
+
+fn part2(target: &Input) -> Output {
+    simulate(target).1
+}
+
+fn simulate(target: &Target) -> (Output, Output) {
+    let (x_min, x_max) = target.x_range;
+    let (y_min, y_max) = target.y_range;
+
+    let mut peak = 0;
+    let mut vels = 0;
+
+    (1..=x_max).for_each(|d_x| {
+        (y_min..=y_min.abs()).for_each(|d_y| {
+            let (hit, y_max) = fire(d_x, d_y, target);
+            if hit {
+                vels += 1;
+                peak = Output::max(peak, y_max);
+            }
+        });
+    });
+
+    (peak, vels)
+}
+
+fn fire(mut x_v: i32, mut y_v: i32, target: &Target) -> (bool, i32) {
+    let mut x = 0;
+    let mut y = 0;
+    let mut peak = y;
+
+    let (x_min, x_max) = target.x_range;
+    let (y_min, y_max) = target.y_range;
+
+    loop {
+        x += x_v;
+        y += y_v;
+
+        x_v -= x_v.signum();
+        y_v -= 1;
+
+        peak = peak.max(y);
+
+        if x >= x_min && x <= x_max && y >= y_min && y <= y_max {
+            return (true, peak);
+        }
+        if x > x_max || y < y_min {
+            return (false, peak);
+        }
+    }
+}
+
+pub struct Target {
+    x_range: (i32, i32),
+    y_range: (i32, i32),
+}
+
+impl FromStr for Target {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (x0, x1, y0, y1) =
+            scan!(line, "target area: x={}..{}, y={}..{}" => i32, i32, i32, i32)?;
+
+        Ok(Self {
+            x_range: (x0, x1),
+            y_range: (y0, y1),
+        })
+    }
+}
@@ -6,9 +6,15 @@ type Output = u32;
 register!(
     "input/day2.txt";
     (input: input!(parse Input)) -> Output {
-        part1(&input);
-        part2(&input);
+        part1(input);
+        part2(input);
     }
+    example r#"forward 5
+                       down 5
+                       forward 8
+                       up 3
+                       down 8
+                       forward 2"# => part1 150, part2 900;
 );
 
 pub enum Direction {
@@ -63,49 +69,3 @@ fn part2(commands: &[Input]) -> Output {
         });
     x * y
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"forward 5
-                       down 5
-                       forward 8
-                       up 3
-                       down 8
-                       forward 2"#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 150);
-        assert_eq!(res2, 900);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 2322630);
-        assert_eq!(res2, 2105273490);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
-}
@@ -1,4 +1,4 @@
-use aoc::PuzzleInput;
+use aoc::{InputError, PuzzleInput};
 use fxhash::{FxBuildHasher, FxHashSet};
 
 type Input = TrenchMap;
@@ -10,6 +10,14 @@ register!(
         part1(&input.map, &input.algo);
         part2(&input.map, &input.algo);
     }
+    example r#"..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+        
+        #..#.
+        #....
+        ##..#
+        ..#..
+        ..###
+        "# => part1 35, part2 3351;
 );
 
 fn part1(map: &FxHashSet<(isize, isize)>, algo: &[bool]) -> Output {
@@ -92,7 +100,7 @@ pub struct TrenchMap {
 impl PuzzleInput for TrenchMap {
     type Out = Self;
 
-    fn from_input(lines: &str) -> Self::Out {
+    fn from_input(lines: &str) -> Result<Self::Out, InputError> {
         let mut lines = aoc::lines(lines);
 
         let algo = lines
@@ -121,54 +129,6 @@ impl PuzzleInput for TrenchMap {
             })
             .collect::<FxHashSet<_>>();
 
-        Self { map, algo }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
-        
-        #..#.
-        #....
-        ##..#
-        ..#..
-        ..###
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 35);
-        assert_eq!(res2, 3351);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 5359);
-        assert_eq!(res2, 12333);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input.map, &input.algo));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input.map, &input.algo));
+        Ok(Self { map, algo })
     }
 }
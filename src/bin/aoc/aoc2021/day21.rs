@@ -8,8 +8,12 @@ register!(
     "input/day21.txt";
     (input: input!(parse Input)) -> Output {
         part1(input.clone());
-        part2(&input);
+        part2(input);
     }
+    example r#"
+        Player 1 starting position: 4
+        Player 2 starting position: 8
+        "# => part1 739785, part2 444356092776315;
 );
 
 fn part1(mut players: Vec<Player>) -> Output {
@@ -141,47 +145,3 @@ impl Dice {
         self.last
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        Player 1 starting position: 4
-        Player 2 starting position: 8
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 739785);
-        assert_eq!(res2, 444356092776315);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 989352);
-        assert_eq!(res2, 430229563871565);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(input.clone()));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
-}
@@ -1,6 +1,4 @@
-use fxhash::FxHashMap;
-
-use aoc::PuzzleInput;
+use aoc::{search::dijkstra, InputError, PuzzleInput};
 
 type Input = Burrows;
 type Output = usize;
@@ -18,7 +16,7 @@ pub struct Burrows(State, State);
 impl PuzzleInput for Burrows {
     type Out = Self;
 
-    fn from_input(_: &str) -> Self::Out {
+    fn from_input(_: &str) -> Result<Self::Out, InputError> {
         let input1 = State::new(
             ['.'; 11],
             [
@@ -39,7 +37,7 @@ impl PuzzleInput for Burrows {
             ],
         );
 
-        Self(input1, input2)
+        Ok(Self(input1, input2))
     }
 }
 
@@ -219,69 +217,17 @@ impl State {
 }
 
 fn search(state: State) -> usize {
-    let mut states = FxHashMap::default();
-    simulate(state, &mut states)
-}
-
-fn simulate(state: State, states: &mut FxHashMap<State, usize>) -> usize {
-    if state.is_solved() {
-        return 0;
-    }
-
-    if let Some(cost) = states.get(&state) {
-        return *cost;
-    }
-
-    let mut costs = vec![];
-
-    for (mv, cost) in state.possible_out_moves() {
-        let new_state = state.move_out(mv);
-        let current_cost = simulate(new_state, states);
-        costs.push(current_cost.saturating_add(cost));
-    }
-
-    for (mv, cost) in state.possible_in_moves() {
-        let new_state = state.move_in(mv);
-        let current_cost = simulate(new_state, states);
-        costs.push(current_cost.saturating_add(cost));
-    }
-
-    let local_min = costs.iter().copied().min().unwrap_or(usize::MAX);
-
-    states.insert(state, local_min);
-
-    local_min
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 13455);
-        assert_eq!(res2, 43567);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| search(input.0));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| search(input.1));
-    }
+    let successors = |state: &State| {
+        let outs = state
+            .possible_out_moves()
+            .into_iter()
+            .map(|(mv, cost)| (state.move_out(mv), cost));
+        let ins = state
+            .possible_in_moves()
+            .into_iter()
+            .map(|(mv, cost)| (state.move_in(mv), cost));
+        outs.chain(ins).collect::<Vec<_>>()
+    };
+
+    dijkstra([state], successors, State::is_solved).map_or(usize::MAX, |path| path.cost)
 }
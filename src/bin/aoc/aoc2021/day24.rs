@@ -75,7 +75,7 @@
 // }
 
 // The goal is to adapt the w's to have 7 push and 7 pop operations.
-use aoc::PuzzleInput;
+use aoc::{InputError, PuzzleInput};
 
 type Input = NoInput;
 type Output = usize;
@@ -170,38 +170,7 @@ pub struct NoInput;
 impl PuzzleInput for NoInput {
     type Out = Self;
 
-    fn from_input(input: &str) -> Self::Out {
-        Self
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 79997391969649);
-        assert_eq!(res2, 16931171414113);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        b.iter(|| part1());
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        b.iter(|| part2());
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
+        Ok(Self)
     }
 }
@@ -8,9 +8,20 @@ type Output = usize;
 register!(
     "input/day25.txt";
     (input: input!(parse Input)) -> Output {
-        part1(input);
+        part1(input.clone());
         part2();
     }
+    example r#"
+        v...>>.vv>
+        .vv>>.vv..
+        >>.>v>...v
+        >>v>>.>.v.
+        v>v.vv.v..
+        >.>>..v...
+        .vv..>.>v.
+        v.v..>>v.v
+        ....v..v.>
+        "# => part1 58, part2 0;
 );
 
 fn part1(mut lines: Vec<Input>) -> Output {
@@ -110,54 +121,3 @@ impl FromStr for Line {
         Ok(Self(s.chars().map(Field::from).collect::<Vec<_>>()))
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        v...>>.vv>
-        .vv>>.vv..
-        >>.>v>...v
-        >>v>>.>.v.
-        v>v.vv.v..
-        >.>>..v...
-        .vv..>.>v.
-        v.v..>>v.v
-        ....v..v.>
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 58);
-        assert_eq!(res2, 0);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 549);
-        assert_eq!(res2, 0);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(input.clone()));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2());
-    }
-}
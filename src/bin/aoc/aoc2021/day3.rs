@@ -9,9 +9,21 @@ type Output = u32;
 register!(
     "input/day3.txt";
     (input: input!(Word)) -> Output {
-        part1(&input);
-        part2(&input);
+        part1(input);
+        part2(input);
     }
+    example r#"00100
+                       11110
+                       10110
+                       10111
+                       10101
+                       01111
+                       00111
+                       11100
+                       10000
+                       11001
+                       00010
+                       01010"# => part1 198, part2 230;
 );
 
 #[derive(Clone, Copy, TryFromPrimitive)]
@@ -152,55 +164,3 @@ fn column_counts<T: AsRef<Word>>(words: &[T]) -> Vec<u32> {
             counts
         })
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"00100
-                       11110
-                       10110
-                       10111
-                       10101
-                       01111
-                       00111
-                       11100
-                       10000
-                       11001
-                       00010
-                       01010"#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 198);
-        assert_eq!(res2, 230);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 3309596);
-        assert_eq!(res2, 2981085);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
-}
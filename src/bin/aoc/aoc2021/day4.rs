@@ -1,21 +1,48 @@
-use std::{collections::HashMap, convert::Infallible, str::FromStr};
+use std::collections::HashMap;
 
-use aoc::{lines, PuzzleInput};
+use aoc::{
+    numbered_lines,
+    parse::{ints, ints_n},
+    InputError, Position, PuzzleInput,
+};
 
-type Input = Bingo;
+type Input = (Vec<u32>, Vec<Board>);
 type Output = u32;
 
 register!(
     "input/day4.txt";
-    (input: input!(verbatim Input)) -> Output {
-        part1(&input.draws, &mut input.boards);
-        part2(&input.draws, &mut input.boards);
+    (input: input!(sections (Draws, input!(blocks Board)))) -> Output {
+        part1(input);
+        part2(input);
     }
+    example r#"
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7
+        "# => part1 4512, part2 1924;
 );
 
-fn part1(draws: &[u32], boards: &mut [Board]) -> Output {
+fn part1((draws, boards): &Input) -> Output {
+    let mut boards = boards.clone();
+
     for draw in draws {
-        for board in boards.iter_mut() {
+        for board in &mut boards {
             board.mark(*draw);
 
             if board.bingo() {
@@ -27,7 +54,8 @@ fn part1(draws: &[u32], boards: &mut [Board]) -> Output {
     Output::MAX
 }
 
-fn part2(draws: &[u32], boards: &mut [Board]) -> Output {
+fn part2((draws, boards): &Input) -> Output {
+    let mut boards = boards.clone();
     let mut boards = boards.iter_mut().collect::<Vec<_>>();
 
     for draw in draws {
@@ -70,7 +98,7 @@ impl Default for Item {
 }
 
 #[derive(Debug, Clone)]
-struct Board {
+pub struct Board {
     size: usize,
     rows: [[Item; SIZE]; SIZE],
     index: HashMap<u32, (usize, usize)>,
@@ -131,22 +159,24 @@ impl Board {
     }
 }
 
-impl FromStr for Board {
-    type Err = Infallible;
+impl PuzzleInput for Board {
+    type Out = Self;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
         let mut rows = [[Item::default(); SIZE]; SIZE];
         let mut index = HashMap::new();
 
-        lines(s).enumerate().for_each(|(i, row)| {
-            row.split_ascii_whitespace()
-                .map(|n| n.parse::<u32>().unwrap())
-                .enumerate()
-                .for_each(|(j, n)| {
-                    index.insert(n, (i, j));
-                    rows[i][j].n = n;
-                });
-        });
+        for (i, (n, line)) in numbered_lines(input).enumerate() {
+            let row = rows.get_mut(i).ok_or_else(|| {
+                InputError::message(line, format!("expected {SIZE} rows")).at(Position::Line(n))
+            })?;
+            let numbers = ints_n::<u32, SIZE>(line).map_err(|e| InputError::line(n, line, e))?;
+
+            for (j, number) in numbers.into_iter().enumerate() {
+                index.insert(number, (i, j));
+                row[j].n = number;
+            }
+        }
 
         Ok(Self {
             size: rows.len(),
@@ -156,86 +186,14 @@ impl FromStr for Board {
     }
 }
 
-pub struct Bingo {
-    draws: Vec<u32>,
-    boards: Vec<Board>,
-}
-
-impl PuzzleInput for Bingo {
-    type Out = Self;
-
-    fn from_input(input: &str) -> Self::Out {
-        let mut blocks = input.split("\n\n");
-
-        let draws = blocks.next().unwrap();
-        let draws = lines(draws)
-            .flat_map(|s| s.split(','))
-            .flat_map(str::parse::<u32>)
-            .collect();
-
-        let boards = blocks.map(|b| b.parse::<Board>().unwrap()).collect();
-
-        Self { draws, boards }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
-
-22 13 17 11  0
- 8  2 23  4 24
-21  9 14 16  7
- 6 10  3 18  5
- 1 12 20 15 19
-
- 3 15  0  2 22
- 9 18 13 17  5
-19  8  7 25 23
-20 11 10 24  4
-14 21 16 12  6
-
-14 21 17 24  4
-10 16 15  9 19
-18  8 23 26 20
-22 11 13  6  5
- 2  0 12  3  7
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 4512);
-        assert_eq!(res2, 1924);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 16674);
-        assert_eq!(res2, 7075);
-    }
+pub struct Draws;
 
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let mut input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input.draws, &mut input.boards));
-    }
+impl PuzzleInput for Draws {
+    type Out = Vec<u32>;
 
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let mut input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input.draws, &mut input.boards));
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
+        ints(input)
+            .collect::<Result<_, _>>()
+            .map_err(|e| InputError::new(input.trim(), e))
     }
 }
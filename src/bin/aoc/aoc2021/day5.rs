@@ -0,0 +1,86 @@
+use std::{collections::HashMap, str::FromStr};
+
+use aoc::{
+    geom::{Point, Point2},
+    parse::{ints_n, ParseError},
+};
+
+type Input = Line;
+type Output = usize;
+
+register!(
+    "input/day5.txt";
+    (input: input!(parse Input)) -> Output {
+        part1(input);
+        part2(input);
+    }
+    example r#"0,9 -> 5,9
+                       8,0 -> 0,8
+                       9,4 -> 3,4
+                       2,2 -> 2,1
+                       7,0 -> 7,4
+                       6,4 -> 2,0
+                       0,9 -> 2,9
+                       3,4 -> 1,4
+                       0,0 -> 8,8
+                       5,5 -> 8,2"# => part1 5, part2 12;
+);
+
+fn part1(lines: &[Input]) -> Output {
+    lines
+        .iter()
+        .filter(|line| line.is_straight())
+        .fold(HashMap::new(), |mut points, line| {
+            line.points()
+                .iter()
+                .for_each(|p| *points.entry(*p).or_insert(0) += 1);
+            points
+        })
+        .values()
+        .filter(|c| **c > 1)
+        .count()
+}
+
+fn part2(lines: &[Input]) -> Output {
+    lines
+        .iter()
+        .fold(HashMap::new(), |mut points, line| {
+            line.points()
+                .iter()
+                .for_each(|p| *points.entry(*p).or_insert(0) += 1);
+            points
+        })
+        .values()
+        .filter(|c| **c > 1)
+        .count()
+}
+
+pub struct Line {
+    start: Point2<i32>,
+    end: Point2<i32>,
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x0, y0, x1, y1] = ints_n(s)?;
+        Ok(Self {
+            start: Point([x0, y0]),
+            end: Point([x1, y1]),
+        })
+    }
+}
+
+impl Line {
+    fn points(&self) -> Vec<Point2<i32>> {
+        let step = (self.end - self.start).signum();
+        let length = self.start.chebyshev(self.end) + 1;
+
+        (0..length).map(|i| self.start + step * i).collect()
+    }
+
+    fn is_straight(&self) -> bool {
+        self.start.x() == self.end.x() || self.start.y() == self.end.y()
+    }
+}
@@ -9,6 +9,7 @@ register!(
         part1(&input.0);
         part2(&input.0);
     }
+    example r#"3,4,3,1,2"# => part1 5934, part2 26984457539;
 );
 
 fn part1(items: &[usize]) -> Output {
@@ -45,44 +46,3 @@ impl FromStr for Fishes {
         Ok(Self(s.split(',').flat_map(str::parse::<usize>).collect()))
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"3,4,3,1,2"#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 5934);
-        assert_eq!(res2, 26984457539);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 377263);
-        assert_eq!(res2, 1695929023803);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input.0));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input.0));
-    }
-}
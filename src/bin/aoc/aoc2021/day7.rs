@@ -9,6 +9,7 @@ register!(
         part1(&input.0);
         part2(&input.0);
     }
+    example r#"16,1,2,0,4,2,7,1,2,14"# => part1 37, part2 168;
 );
 
 fn part1(positions: &[i32]) -> Output {
@@ -44,44 +45,3 @@ impl FromStr for Crabs {
         Ok(Self(s.split(',').flat_map(str::parse::<i32>).collect()))
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"16,1,2,0,4,2,7,1,2,14"#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 37);
-        assert_eq!(res2, 168);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 326132);
-        assert_eq!(res2, 88612508);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input.0));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input.0));
-    }
-}
@@ -0,0 +1,67 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc::grid::{Grid, Pos};
+
+type Input = Grid<u8>;
+type Output = u32;
+
+register!(
+    "input/day9.txt";
+    (input: input!(grid u8)) -> Output {
+        part1(input).0;
+        part2(input);
+    }
+    example r#"2199943210
+                       3987894921
+                       9856789892
+                       8767896789
+                       9899965678"# => part1 15, part2 1134;
+);
+
+fn part1(field: &Input) -> (Output, Vec<Pos>) {
+    let mut sum = 0_u32;
+    let mut depths = vec![];
+
+    for (pos, &ctr) in field.iter() {
+        if field.neighbors4(pos).all(|n| field[n] > ctr) {
+            depths.push(pos);
+            sum += u32::from(ctr - b'0') + 1;
+        }
+    }
+
+    (sum, depths)
+}
+
+fn part2(field: &Input) -> Output {
+    fn bfs(field: &Input, root: Pos) -> u32 {
+        let mut size = 0;
+        let mut queue = VecDeque::new();
+        queue.push_back(root);
+
+        let mut seen = HashSet::new();
+
+        while let Some(pos) = queue.pop_front() {
+            let ctr = field[pos];
+            if ctr == b'9' {
+                continue;
+            }
+            size += 1;
+
+            for p in field.neighbors4(pos) {
+                if field[p] > ctr && seen.insert(p) {
+                    queue.push_back(p);
+                }
+            }
+        }
+        size
+    }
+
+    let (_, depths) = part1(field);
+    let mut sizes = depths
+        .iter()
+        .map(|depth| bfs(field, *depth))
+        .collect::<Vec<_>>();
+    sizes.sort_by(|a, b| a.cmp(b).reverse());
+
+    sizes.iter().take(3).product()
+}
@@ -0,0 +1,65 @@
+use aoc::{numbered_lines, InputError, PuzzleInput};
+
+type Input = Calories;
+type Output = u32;
+
+register!(
+    "input/day1.txt";
+    (input: input!(blocks Input)) -> Output {
+        part1(input);
+        part2(input);
+    }
+    example r#"
+        1000
+        2000
+        3000
+
+        4000
+
+        5000
+        6000
+
+        7000
+        8000
+        9000
+
+        10000
+        "# => part1 24000, part2 45000;
+);
+
+fn part1(items: &[Input]) -> Output {
+    items.iter().map(|c| c.0).max().unwrap_or_default()
+}
+
+fn part2(items: &[Input]) -> Output {
+    let (a, b, c) = items.iter().map(|c| c.0).fold((0, 0, 0), |acc, n| {
+        if n >= acc.0 {
+            (n, acc.0, acc.1)
+        } else if n >= acc.1 {
+            (acc.0, n, acc.1)
+        } else if n >= acc.2 {
+            (acc.0, acc.1, n)
+        } else {
+            acc
+        }
+    });
+
+    a + b + c
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct Calories(u32);
+
+impl PuzzleInput for Calories {
+    type Out = Self;
+
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
+        numbered_lines(input)
+            .map(|(n, line)| {
+                line.parse::<u32>()
+                    .map_err(|e| InputError::line(n, line, e))
+            })
+            .sum::<Result<_, _>>()
+            .map(Self)
+    }
+}
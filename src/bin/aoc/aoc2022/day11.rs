@@ -1,4 +1,4 @@
-use aoc::{lines, PuzzleInput};
+use aoc::{lines, InputError, PuzzleInput};
 
 type Input = Monkey;
 type Output = usize;
@@ -7,8 +7,37 @@ register!(
     "input/day11.txt";
     (input: input!(blocks Input)) -> Output {
         part1(input.clone());
-        part2(input);
+        part2(input.clone());
     }
+    example r#"
+        Monkey 0:
+          Starting items: 79, 98
+          Operation: new = old * 19
+          Test: divisible by 23
+            If true: throw to monkey 2
+            If false: throw to monkey 3
+
+        Monkey 1:
+          Starting items: 54, 65, 75, 74
+          Operation: new = old + 6
+          Test: divisible by 19
+            If true: throw to monkey 2
+            If false: throw to monkey 0
+
+        Monkey 2:
+          Starting items: 79, 60, 97
+          Operation: new = old * old
+          Test: divisible by 13
+            If true: throw to monkey 1
+            If false: throw to monkey 3
+
+        Monkey 3:
+          Starting items: 74
+          Operation: new = old + 3
+          Test: divisible by 17
+            If true: throw to monkey 0
+            If false: throw to monkey 1
+        "# => part1 10605, part2 2713310158;
 );
 
 fn part1(monkeys: Vec<Input>) -> Output {
@@ -87,7 +116,7 @@ pub struct Monkey {
 impl PuzzleInput for Monkey {
     type Out = Self;
 
-    fn from_input(block: &str) -> Self::Out {
+    fn from_input(block: &str) -> Result<Self::Out, InputError> {
         let mut lines = lines(block);
         let _ = lines.next(); // Monkey i:
         let items = lines // Starting items: 79, 98
@@ -132,81 +161,12 @@ impl PuzzleInput for Monkey {
             .map(|n| n.parse::<usize>().unwrap())
             .unwrap();
 
-        Self {
+        Ok(Self {
             items,
             operation,
             divisor,
             inspections: 0,
             targets: (true_target, false_target),
-        }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        Monkey 0:
-          Starting items: 79, 98
-          Operation: new = old * 19
-          Test: divisible by 23
-            If true: throw to monkey 2
-            If false: throw to monkey 3
-
-        Monkey 1:
-          Starting items: 54, 65, 75, 74
-          Operation: new = old + 6
-          Test: divisible by 19
-            If true: throw to monkey 2
-            If false: throw to monkey 0
-
-        Monkey 2:
-          Starting items: 79, 60, 97
-          Operation: new = old * old
-          Test: divisible by 13
-            If true: throw to monkey 1
-            If false: throw to monkey 3
-
-        Monkey 3:
-          Starting items: 74
-          Operation: new = old + 3
-          Test: divisible by 17
-            If true: throw to monkey 0
-            If false: throw to monkey 1
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 10605);
-        assert_eq!(res2, 2713310158);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 50616);
-        assert_eq!(res2, 11309046332);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(input.clone()));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(input.clone()));
+        })
     }
 }
@@ -0,0 +1,74 @@
+use aoc::{
+    grid::{Grid, Pos},
+    search::bfs,
+    InputError, PuzzleInput,
+};
+
+type Input = Map;
+type Output = usize;
+
+register!(
+    "input/day12.txt";
+    (input: input!(verbatim Input)) -> Output {
+        part1(input);
+        part2(input);
+    }
+    example r#"
+        Sabqponm
+        abcryxxl
+        accszExk
+        acctuvwj
+        abdefghi
+        "# => part1 31, part2 29;
+);
+
+fn part1(map: &Input) -> Output {
+    shortest(map, [map.start])
+}
+
+fn part2(map: &Input) -> Output {
+    let sources = map
+        .grid
+        .iter()
+        .filter_map(|(pos, b)| (*b == b'a').then_some(pos));
+    shortest(map, sources)
+}
+
+fn shortest<I>(map: &Input, sources: I) -> Output
+where
+    I: IntoIterator<Item = Pos>,
+{
+    let grid = &map.grid;
+    let climbable = |&pos: &Pos| {
+        grid.neighbors4(pos)
+            .filter(move |&next| grid[next] <= grid[pos] + 1)
+    };
+
+    bfs(sources, climbable, |&pos| pos == map.end).map_or(0, |path| path.cost)
+}
+
+#[derive(Debug)]
+pub struct Map {
+    grid: Grid<u8>,
+    start: Pos,
+    end: Pos,
+}
+
+impl PuzzleInput for Map {
+    type Out = Self;
+
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
+        let mut grid = Grid::<u8>::from_input(input)?;
+        let start = grid
+            .position(|b| *b == b'S')
+            .ok_or_else(|| InputError::message("", "missing start"))?;
+        let end = grid
+            .position(|b| *b == b'E')
+            .ok_or_else(|| InputError::message("", "missing end"))?;
+
+        grid[start] = b'a';
+        grid[end] = b'z' + 1;
+
+        Ok(Self { grid, start, end })
+    }
+}
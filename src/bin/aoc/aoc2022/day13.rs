@@ -5,7 +5,7 @@ use std::{
     slice::{self, Iter},
 };
 
-use aoc::{lines, PuzzleInput};
+use aoc::{lines, InputError, PuzzleInput};
 
 type Input = Pair;
 type Output = usize;
@@ -13,9 +13,34 @@ type Output = usize;
 register!(
     "input/day13.txt";
     (input: input!(blocks Input)) -> Output {
-        part1(&input);
-        part2(&input);
+        part1(input);
+        part2(input);
     }
+    example r#"
+        [1,1,3,1,1]
+        [1,1,5,1,1]
+
+        [[1],[2,3,4]]
+        [[1],4]
+
+        [9]
+        [[8,7,6]]
+
+        [[4,4],4,4]
+        [[4,4],4,4,4]
+
+        [7,7,7,7]
+        [7,7,7]
+
+        []
+        [3]
+
+        [[[]]]
+        [[]]
+
+        [1,[2,[3,[4,[5,6,7]]]],8,9]
+        [1,[2,[3,[4,[5,6,0]]]],8,9]
+        "# => part1 13, part2 140;
 );
 
 fn part1(items: &[Input]) -> Output {
@@ -58,9 +83,9 @@ pub struct Pair(Node, Node);
 impl PuzzleInput for Pair {
     type Out = Self;
 
-    fn from_input(input: &str) -> Self::Out {
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
         let mut lines = lines(input);
-        Self(lines.next().unwrap().into(), lines.next().unwrap().into())
+        Ok(Self(lines.next().unwrap().into(), lines.next().unwrap().into()))
     }
 }
 
@@ -127,68 +152,3 @@ impl<'a> From<&'a str> for Node {
         parse(&mut value.as_bytes()[1..].iter().peekable())
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        [1,1,3,1,1]
-        [1,1,5,1,1]
-
-        [[1],[2,3,4]]
-        [[1],4]
-
-        [9]
-        [[8,7,6]]
-
-        [[4,4],4,4]
-        [[4,4],4,4,4]
-
-        [7,7,7,7]
-        [7,7,7]
-
-        []
-        [3]
-
-        [[[]]]
-        [[]]
-
-        [1,[2,[3,[4,[5,6,7]]]],8,9]
-        [1,[2,[3,[4,[5,6,0]]]],8,9]
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 13);
-        assert_eq!(res2, 140);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 5825);
-        assert_eq!(res2, 24477);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
-}
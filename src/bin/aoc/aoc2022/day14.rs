@@ -0,0 +1,225 @@
+use aoc::{
+    grid::Grid,
+    numbered_lines,
+    parse::ints_n,
+    viz::{Recorder, Rgb, BLACK},
+    InputError, PuzzleInput,
+};
+
+type Input = Cave;
+type Output = usize;
+
+register!(
+    "input/day14.txt";
+    (input: input!(verbatim Input)) -> Output {
+        part1(input.clone());
+        part2(input.clone());
+    }
+    example r#"
+        498,4 -> 498,6 -> 496,6
+        503,4 -> 502,4 -> 502,9 -> 494,9
+        "# => part1 24, part2 93;
+);
+
+/// Number of sand units that come to rest between two recorded frames.
+const FRAME_EVERY: usize = 100;
+
+fn part1(mut cave: Input) -> Output {
+    let mut recorder = Recorder::new("2022/day14/part1").scale(2);
+    let mut i = 0;
+    loop {
+        match cave.enter_sand_man() {
+            Some(sand) => {
+                cave.record(&mut recorder, i, i + sand);
+                i += sand;
+            }
+            None => break i,
+        }
+    }
+}
+
+fn part2(mut cave: Input) -> Output {
+    let max_y = cave.max_y() + 2;
+    cave.fill((0, max_y), (cave.map.width() - 1, max_y));
+
+    let mut recorder = Recorder::new("2022/day14/part2").scale(2);
+    let mut i = 0;
+    loop {
+        match cave.enter_more_sand_man(max_y) {
+            Some(sand) => {
+                cave.record(&mut recorder, i, i + sand);
+                i += sand;
+            }
+            None => break i,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd)]
+pub enum Cell {
+    Rock,
+    Sand,
+    Air,
+}
+
+impl Cell {
+    fn color(self) -> Rgb {
+        match self {
+            Self::Rock => [128, 128, 128],
+            Self::Sand => [237, 201, 175],
+            Self::Air => BLACK,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cave {
+    map: Grid<Cell>,
+    /// The `(x, y)` positions the current particle has fallen through.
+    /// The next particle takes the same way up to where this one rests,
+    /// so it continues from the end of the path.
+    path: Vec<(usize, usize)>,
+}
+
+/// Where the sand pours in, as `(x, y)`.
+const SOURCE: (usize, usize) = (500, 0);
+
+impl Cave {
+    fn enter_sand_man(&mut self) -> Option<usize> {
+        self.drop_sand(None)
+    }
+
+    fn enter_more_sand_man(&mut self, max_y: usize) -> Option<usize> {
+        if self.map[(SOURCE.1, SOURCE.0)] == Cell::Air {
+            self.drop_sand(Some(max_y))
+        } else {
+            None
+        }
+    }
+
+    // Returns how many sand particles have been added (if any)
+    fn drop_sand(&mut self, floor: Option<usize>) -> Option<usize> {
+        let (width, height) = (self.map.width(), self.map.height());
+        if self.path.is_empty() {
+            self.path.push(SOURCE);
+        }
+
+        loop {
+            let (x, y) = *self.path.last()?;
+
+            // Part 1
+            if y == height - 1 {
+                return None;
+            }
+
+            // Part 2
+            // If we hit the horizontal boundaries, we know that the particles
+            // will just fall down to the side without any rocks in their way.
+            // This means, that we will add as many sand particles as we have
+            // distance to the floor (max_y). We have to add one sand particle
+            // to bubble this up eventually.
+            //
+            // I think the flaw here is that it won't work if we have rocks
+            // placed at the x boundaries. Without a floor (part 1), the
+            // particles fall into the abyss instead.
+            if x == 0 || x == width - 1 {
+                let floor = floor?;
+                self.rest(x, y);
+                return Some(floor - y);
+            }
+
+            let below = self.map.row(y + 1);
+            match below[x] {
+                Cell::Air => self.path.push((x, y + 1)),
+                Cell::Rock | Cell::Sand => match (below[x - 1], below[x + 1]) {
+                    (Cell::Air, _) => self.path.push((x - 1, y + 1)),
+                    (_, Cell::Air) => self.path.push((x + 1, y + 1)),
+                    _ => {
+                        self.rest(x, y);
+                        return Some(1);
+                    }
+                },
+            }
+        }
+    }
+
+    /// Lets the current particle come to rest at the end of its path.
+    fn rest(&mut self, x: usize, y: usize) {
+        self.path.pop();
+        self.map[(y, x)] = Cell::Sand;
+    }
+
+    /// Places rock on the line between the `(x, y)` points `from` and `to`.
+    fn fill(&mut self, mut from: (usize, usize), mut to: (usize, usize)) {
+        if from > to {
+            std::mem::swap(&mut from, &mut to);
+        }
+        let (x0, y0) = from;
+        let (x1, y1) = to;
+
+        for x in x0..=x1 {
+            self.map[(y0, x)] = Cell::Rock;
+        }
+        for y in y0..=y1 {
+            self.map[(y, x0)] = Cell::Rock;
+        }
+    }
+
+    /// Records a frame whenever the number of resting sand units, which
+    /// can grow by more than one per drop, passes a multiple of `FRAME_EVERY`.
+    fn record(&self, recorder: &mut Recorder, before: usize, after: usize) {
+        if before / FRAME_EVERY != after / FRAME_EVERY {
+            recorder.frame(self.map.width(), self.map.height(), |pos| {
+                self.map[pos].color()
+            });
+        }
+    }
+
+    fn max_y(&self) -> usize {
+        self.map.height()
+            - 1
+            - self
+                .map
+                .rows()
+                .rev()
+                .position(|row| row.iter().any(|c| *c == Cell::Rock || *c == Cell::Sand))
+                .unwrap_or(0)
+    }
+}
+
+impl PuzzleInput for Cave {
+    type Out = Self;
+
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
+        let paths = numbered_lines(input)
+            .map(|(n, line)| {
+                line.split(" -> ")
+                    .map(|pair| ints_n(pair).map(<(usize, usize)>::from))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| InputError::line(n, line, e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Leave room for the floor two rows below the lowest rock, and keep
+        // an empty column between the rightmost rock and the right edge,
+        // where `drop_sand` takes its shortcut.
+        let max_y = paths.iter().flatten().map(|&(_, y)| y).max().unwrap_or(0);
+        let height = max_y + 3;
+        let max_x = paths.iter().flatten().map(|&(x, _)| x).max().unwrap_or(0);
+        let width = max_x.max(SOURCE.0) + 3;
+
+        let mut cave = Self {
+            map: Grid::filled(width, height, Cell::Air),
+            path: Vec::new(),
+        };
+
+        for path in paths {
+            path.into_iter().reduce(|prev, next| {
+                cave.fill(prev, next);
+                next
+            });
+        }
+
+        Ok(cave)
+    }
+}
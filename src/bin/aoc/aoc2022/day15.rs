@@ -0,0 +1,132 @@
+use std::ops::RangeInclusive;
+
+use aoc::{
+    geom::Point, numbered_lines, parse::ints_n, ranges::IntervalSet, InputError, PuzzleInput,
+};
+use fxhash::FxHashSet;
+
+type Input = Map;
+type Output = usize;
+
+register!(
+    "input/day15.txt";
+    (input: input!(verbatim Input)) -> Output {
+        part1(input);
+        part2(input);
+    }
+    example r#"
+        Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+        Sensor at x=9, y=16: closest beacon is at x=10, y=16
+        Sensor at x=13, y=2: closest beacon is at x=15, y=3
+        Sensor at x=12, y=14: closest beacon is at x=10, y=16
+        Sensor at x=10, y=20: closest beacon is at x=10, y=16
+        Sensor at x=14, y=17: closest beacon is at x=10, y=16
+        Sensor at x=8, y=7: closest beacon is at x=2, y=10
+        Sensor at x=2, y=0: closest beacon is at x=2, y=10
+        Sensor at x=0, y=11: closest beacon is at x=2, y=10
+        Sensor at x=20, y=14: closest beacon is at x=25, y=17
+        Sensor at x=17, y=20: closest beacon is at x=21, y=22
+        Sensor at x=16, y=7: closest beacon is at x=15, y=3
+        Sensor at x=14, y=3: closest beacon is at x=15, y=3
+        Sensor at x=20, y=1: closest beacon is at x=15, y=3
+        "# => part1 26, part2 56000011;
+);
+
+fn part1(map: &Input) -> Output {
+    if map.is_example() {
+        map.coverage(10)
+    } else {
+        map.coverage(2_000_000)
+    }
+}
+
+fn part2(map: &Input) -> Output {
+    if map.is_example() {
+        map.tuning_frequency(20)
+    } else {
+        map.tuning_frequency(4_000_000)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Sensor {
+    x: i32,
+    y: i32,
+    range: i32,
+}
+
+impl Sensor {
+    fn coverage_at_row(&self, y: i32) -> Option<RangeInclusive<i32>> {
+        let dy = self.y.abs_diff(y) as i32;
+        if dy > self.range {
+            None
+        } else {
+            let dx = self.range.abs_diff(dy) as i32;
+            Some(self.x - dx..=self.x + dx)
+        }
+    }
+}
+
+pub struct Map {
+    sensors: Vec<Sensor>,
+    beacons: FxHashSet<(i32, i32)>,
+}
+
+impl Map {
+    fn is_example(&self) -> bool {
+        self.sensors.len() == 14
+    }
+
+    fn covered(&self, y: i32) -> IntervalSet<i32> {
+        self.sensors
+            .iter()
+            .filter_map(|s| s.coverage_at_row(y))
+            .collect()
+    }
+
+    fn coverage(&self, y: i32) -> usize {
+        let covered = self.covered(y);
+
+        let beacons = self
+            .beacons
+            .iter()
+            .filter(|(b_x, b_y)| *b_y == y && covered.contains(*b_x))
+            .count();
+
+        covered.len() as usize - beacons
+    }
+
+    fn tuning_frequency(&self, x_y_max: i32) -> usize {
+        (0..x_y_max)
+            .rev()
+            .find_map(|y| {
+                let mut gaps = self.covered(y).gaps(0..x_y_max + 1);
+                gaps.next().map(|gap| gap.start as usize * 4_000_000 + y as usize)
+            })
+            .unwrap_or(usize::MAX)
+    }
+}
+
+impl PuzzleInput for Map {
+    type Out = Self;
+
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
+        let mut beacons = FxHashSet::default();
+
+        let sensors = numbered_lines(input)
+            .map(|(n, line)| {
+                let [s_x, s_y, b_x, b_y] = ints_n(line).map_err(|e| InputError::line(n, line, e))?;
+                let range = Point([s_x, s_y]).manhattan(Point([b_x, b_y]));
+
+                beacons.insert((b_x, b_y));
+                Ok(Sensor {
+                    x: s_x,
+                    y: s_y,
+                    range,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { sensors, beacons })
+    }
+}
@@ -1,7 +1,6 @@
-use std::cell::RefCell;
 use tap::prelude::*;
 
-use aoc::{lines, PuzzleInput};
+use aoc::{lines, memo::Memo, InputError, PuzzleInput};
 use atoi::FromRadix10;
 use fxhash::FxHashMap;
 
@@ -11,31 +10,59 @@ type Output = u32;
 register!(
     "input/day16.txt";
     (input: input!(verbatim Input)) -> Output {
-        part1(&input);
-        part2(&input);
+        part1(input);
+        part2(input);
     }
+    example r#"
+        Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+        Valve BB has flow rate=13; tunnels lead to valves CC, AA
+        Valve CC has flow rate=2; tunnels lead to valves DD, BB
+        Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+        Valve EE has flow rate=3; tunnels lead to valves FF, DD
+        Valve FF has flow rate=0; tunnels lead to valves EE, GG
+        Valve GG has flow rate=0; tunnels lead to valves FF, HH
+        Valve HH has flow rate=22; tunnel leads to valve GG
+        Valve II has flow rate=0; tunnels lead to valves AA, JJ
+        Valve JJ has flow rate=21; tunnel leads to valve II
+        "# => part1 1651, part2 1707;
 );
 
 fn part1(pipes: &Input) -> Output {
-    max_pressure(
-        pipes.nodes["AA"],
-        &pipes.flows,
-        &pipes.distances(),
-        30,
-        false,
-        pipes.nodes["AA"],
-    )
+    let mut memo = Memo::new();
+    let distances = span!("distances", pipes.distances());
+    let max = span!(
+        "max_pressure",
+        max_pressure(
+            &mut memo,
+            pipes.nodes["AA"],
+            &pipes.flows,
+            &distances,
+            30,
+            false,
+            pipes.nodes["AA"],
+        )
+    );
+    debug!("{memo}");
+    max
 }
 
 fn part2(pipes: &Input) -> Output {
-    max_pressure(
-        pipes.nodes["AA"],
-        &pipes.flows,
-        &pipes.distances(),
-        26,
-        true,
-        pipes.nodes["AA"],
-    )
+    let mut memo = Memo::new();
+    let distances = span!("distances", pipes.distances());
+    let max = span!(
+        "max_pressure",
+        max_pressure(
+            &mut memo,
+            pipes.nodes["AA"],
+            &pipes.flows,
+            &distances,
+            26,
+            true,
+            pipes.nodes["AA"],
+        )
+    );
+    debug!("{memo}");
+    max
 }
 
 #[derive(PartialEq, Eq, Hash)]
@@ -61,11 +88,8 @@ impl Args {
     }
 }
 
-std::thread_local! {
-    pub static MEMOIZED_MAX_PRESSURE: RefCell<FxHashMap<Args, Output>> = RefCell::new(FxHashMap::default());
-}
-
 fn max_pressure(
+    memo: &mut Memo<Args, Output>,
     curr: usize,
     flows: &FxHashMap<usize, u32>,
     distances: &[Vec<u32>],
@@ -75,43 +99,39 @@ fn max_pressure(
 ) -> Output {
     let args = Args::new(curr, flows, time_left, use_elephant);
 
-    if let Some(max) = MEMOIZED_MAX_PRESSURE.with(|m| m.borrow().get(&args).copied()) {
-        return max;
-    }
+    memo.get_or_insert_with(args, |memo| {
+        // Figure out, if its better to let the elephant process the remanings flows.
+        let elephant = use_elephant
+            .then(|| max_pressure(memo, aa_node_id, flows, distances, 26, false, aa_node_id));
 
-    let max = flows
-        .iter()
-        // Only visit the node if there is enough time to get there and open the valve.
-        // We need 1 minute * distance to get to `next` plus 1 minute to open the valve.
-        .filter(|(next, _)| distances[curr][**next] + 1 < time_left)
-        .map(|(next, flow)| {
-            // Only look at the remaining possible flows.
-            let mut flows = flows.clone();
-            flows.remove(next);
-            // It takes us `distance` minutes to get to n and 1 minute to open the valve.
-            let time_left = time_left - distances[curr][*next] - 1;
-            // Therefore, the valve will be open for `time_left` minutes.
-            let cost = time_left * flow;
-            // Find the maximum pressure for the remaning flows.
-            cost + max_pressure(
-                *next,
-                &flows,
-                distances,
-                time_left,
-                use_elephant,
-                aa_node_id,
-            )
-        })
-        .chain(
-            // Figure out, if its better to let the elephant process the remanings flows.
-            use_elephant.then(|| max_pressure(aa_node_id, flows, distances, 26, false, aa_node_id)),
-        )
-        .max()
-        .unwrap_or_default();
-
-    MEMOIZED_MAX_PRESSURE
-        .with(|m| m.borrow_mut().insert(args, max))
-        .unwrap_or(max)
+        flows
+            .iter()
+            // Only visit the node if there is enough time to get there and open the valve.
+            // We need 1 minute * distance to get to `next` plus 1 minute to open the valve.
+            .filter(|(next, _)| distances[curr][**next] + 1 < time_left)
+            .map(|(next, flow)| {
+                // Only look at the remaining possible flows.
+                let mut flows = flows.clone();
+                flows.remove(next);
+                // It takes us `distance` minutes to get to n and 1 minute to open the valve.
+                let time_left = time_left - distances[curr][*next] - 1;
+                // Therefore, the valve will be open for `time_left` minutes.
+                let cost = time_left * flow;
+                // Find the maximum pressure for the remaning flows.
+                cost + max_pressure(
+                    memo,
+                    *next,
+                    &flows,
+                    distances,
+                    time_left,
+                    use_elephant,
+                    aa_node_id,
+                )
+            })
+            .chain(elephant)
+            .max()
+            .unwrap_or_default()
+    })
 }
 
 #[derive(Debug)]
@@ -161,7 +181,7 @@ impl Pipes {
 impl PuzzleInput for Pipes {
     type Out = Self;
 
-    fn from_input(input: &str) -> Self::Out {
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
         let mut nodes = FxHashMap::<String, usize>::default();
         let mut flows = FxHashMap::<usize, u32>::default();
         let mut edges = FxHashMap::<usize, Vec<usize>>::default();
@@ -194,62 +214,10 @@ impl PuzzleInput for Pipes {
             edges.insert(id, targets);
         });
 
-        Self {
+        Ok(Self {
             nodes,
             flows,
             edges,
-        }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
-        Valve BB has flow rate=13; tunnels lead to valves CC, AA
-        Valve CC has flow rate=2; tunnels lead to valves DD, BB
-        Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
-        Valve EE has flow rate=3; tunnels lead to valves FF, DD
-        Valve FF has flow rate=0; tunnels lead to valves EE, GG
-        Valve GG has flow rate=0; tunnels lead to valves FF, HH
-        Valve HH has flow rate=22; tunnel leads to valve GG
-        Valve II has flow rate=0; tunnels lead to valves AA, JJ
-        Valve JJ has flow rate=21; tunnel leads to valve II
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 1651);
-        assert_eq!(res2, 1707);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 1775);
-        assert_eq!(res2, 2351);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
+        })
     }
 }
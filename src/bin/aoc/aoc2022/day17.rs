@@ -1,5 +1,9 @@
-use aoc::PuzzleInput;
-use fxhash::{FxBuildHasher, FxHashMap, FxHashSet};
+use aoc::{
+    cycle,
+    viz::{Recorder, Rgb, BLACK},
+    InputError, PuzzleInput,
+};
+use fxhash::{FxBuildHasher, FxHashSet};
 use tap::Tap;
 
 type Input = Pattern;
@@ -8,65 +12,51 @@ type Output = usize;
 register!(
     "input/day17.txt";
     (input: input!(verbatim Input)) -> Output {
-        part1(&input);
-        part2(&input);
+        part1(input);
+        part2(input);
     }
+    example r#"
+        >>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>
+        "# => part1 3068, part2 1514285714288;
 );
 
 fn part1(pattern: &Input) -> Output {
     let mut chamber = Chamber::new();
+    let mut recorder = Recorder::new("2022/day17").scale(8);
     let mut mov = 0;
 
     for it in 0..2022 {
         let rock_type = RockType::from(it % 5);
         mov = chamber.add_rock(rock_type, &pattern.0, mov);
+        chamber.record(&mut recorder);
     }
 
     chamber.top()
 }
 
 fn part2(pattern: &Input) -> Output {
-    let mut chamber = Chamber::new();
-    let mut mov = 0;
-    let mut cache = FxHashMap::default();
-    let mut extra = 0;
-
-    let iterations = 1_000_000_000_000;
-    let mut it = 0;
-
-    while it < iterations {
-        let rock_idx = it % 5;
-        let rock_type = RockType::from(rock_idx);
-        mov = chamber.add_rock(rock_type, &pattern.0, mov);
-        // Check if there is a repetition in the chamber.
-        // If we detect a repetition for the same shape
-        // and the same movement index, we can use that
-        // to skip iterations.
-        cache
-            .entry((rock_idx, mov, chamber.top_n(42)))
-            .and_modify(|(prev_i, prev_top)| {
-                // The increase in the height of the chamber.
-                let dt = chamber.top - *prev_top;
-                // The number of iterations it took to repeat the pattern.
-                let di = it - *prev_i;
-                // The number of times we can repeat the pattern before
-                // we hit the total number of iterations.
-                let reps = (iterations - it) / di;
-                // Skip iterations.
-                it += reps * di;
-                // Adjust the height of the chamber.
-                extra += reps * dt;
-            })
-            .or_insert((it, chamber.top));
-
-        it += 1;
-    }
-
-    chamber.top() + extra
+    let cycle = cycle::find(
+        (Chamber::new(), 0, 0),
+        |(chamber, mov, rocks)| {
+            *mov = chamber.add_rock(RockType::from(*rocks % 5), &pattern.0, *mov);
+            *rocks += 1;
+        },
+        // The chamber repeats once the same shape falls at the
+        // same movement index onto the same skyline.
+        |(chamber, mov, rocks)| (*rocks % 5, *mov, chamber.top_n(42)),
+        |(chamber, _, _)| chamber.top,
+    );
+
+    cycle.extrapolate(1_000_000_000_000)
 }
 
 const WIDTH: usize = 7;
 
+/// Number of rows below the top of the tower that are recorded.
+const FRAME_HEIGHT: usize = 40;
+
+const ROCK: Rgb = [170, 120, 80];
+
 #[derive(Clone, Copy)]
 pub enum RockType {
     HLine,
@@ -265,6 +255,15 @@ impl Chamber {
             .collect::<Vec<_>>()
             .tap_mut(|v| v.sort_unstable())
     }
+
+    fn record(&self, recorder: &mut Recorder) {
+        recorder.frame(WIDTH, FRAME_HEIGHT, |(row, x)| {
+            match self.top.checked_sub(row) {
+                Some(y) if self.rocks.contains(&(x, y)) => ROCK,
+                _ => BLACK,
+            }
+        });
+    }
 }
 
 #[derive(Debug)]
@@ -279,8 +278,8 @@ pub enum Move {
 impl PuzzleInput for Pattern {
     type Out = Self;
 
-    fn from_input(input: &str) -> Self::Out {
-        Self(
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
+        Ok(Self(
             input
                 .trim()
                 .bytes()
@@ -290,49 +289,6 @@ impl PuzzleInput for Pattern {
                     _ => panic!("unexpected char {b}"),
                 })
                 .collect::<Vec<_>>(),
-        )
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        >>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 3068);
-        assert_eq!(res2, 1514285714288);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 3197);
-        assert_eq!(res2, 1568513119571);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
+        ))
     }
 }
@@ -0,0 +1,64 @@
+use aoc::geom::{Point, Point3};
+use fxhash::FxHashSet;
+
+type Input = Point3<isize>;
+type Output = usize;
+
+register!(
+    "input/day18.txt";
+    (input: input!(parse Input)) -> Output {
+        part1(input);
+        part2(input);
+    }
+    example r#"
+        2,2,2
+        1,2,2
+        3,2,2
+        2,1,2
+        2,3,2
+        2,2,1
+        2,2,3
+        2,2,4
+        2,2,6
+        1,2,5
+        3,2,5
+        2,1,5
+        2,3,5
+        "# => part1 64, part2 58;
+);
+
+fn part1(points: &[Input]) -> Output {
+    let set = points.iter().collect::<FxHashSet<_>>();
+    points
+        .iter()
+        .map(|p| p.neighbors().filter(|p| !set.contains(p)).count())
+        .sum()
+}
+
+fn part2(points: &[Input]) -> Output {
+    // Discover the surrounding space of the lava droplet using
+    // a bounded depth-first-search. Then we can check which
+    // cubes have a side facing the outside.
+    let (p_min, p_max) = Point::bounds(points.iter().copied()).unwrap();
+    let (p_min, p_max) = (p_min - Point([1; 3]), p_max + Point([1; 3]));
+
+    let point_set = points.iter().collect::<FxHashSet<_>>();
+    let mut seen = FxHashSet::default();
+    let mut stack = vec![p_min];
+
+    loop {
+        let Some(next) = stack.pop() else {
+            break;
+        };
+
+        if next.within(p_min, p_max) && !point_set.contains(&next) && !seen.contains(&next) {
+            seen.insert(next);
+            next.neighbors().for_each(|p| stack.push(p));
+        }
+    }
+
+    points
+        .iter()
+        .map(|p| p.neighbors().filter(|p| seen.contains(p)).count())
+        .sum()
+}
@@ -6,9 +6,14 @@ type Output = u32;
 register!(
     "input/day2.txt";
     (input: input!(parse Input)) -> Output {
-        part1(&input);
-        part2(&input);
+        part1(input);
+        part2(input);
     }
+    example r#"
+        A Y
+        B X
+        C Z
+        "# => part1 15, part2 12;
 );
 
 fn part1(items: &[Input]) -> Output {
@@ -117,48 +122,3 @@ impl From<char> for Outcome {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        A Y
-        B X
-        C Z
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 15);
-        assert_eq!(res2, 12);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 11603);
-        assert_eq!(res2, 12725);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
-}
@@ -4,9 +4,18 @@ type Output = i64;
 register!(
     "input/day20.txt";
     (input: input!(parse Input)) -> Output {
-        part1(&input);
-        part2(&input);
+        part1(input);
+        part2(input);
     }
+    example r#"
+        1
+        2
+        -3
+        3
+        -2
+        0
+        4
+        "# => part1 3, part2 1623178306;
 );
 
 fn part1(items: &[Input]) -> Output {
@@ -39,52 +48,3 @@ fn mix(items: &[Input], dec_key: i64, rounds: usize) -> Output {
         .map(|i| items[idx[(idx_0_new + i) % len]] * dec_key)
         .sum()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        1
-        2
-        -3
-        3
-        -2
-        0
-        4
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 3);
-        assert_eq!(res2, 1623178306);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 13522);
-        assert_eq!(res2, 17113168880158);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
-}
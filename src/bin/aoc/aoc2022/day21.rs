@@ -1,6 +1,6 @@
 use std::str;
 
-use aoc::{lines, PuzzleInput};
+use aoc::{lines, InputError, PuzzleInput};
 use atoi::FromRadix10;
 use fxhash::{FxHashMap, FxHashSet};
 
@@ -10,9 +10,26 @@ type Output = isize;
 register!(
     "input/day21.txt";
     (input: input!(verbatim Input)) -> Output {
-        part1(&input);
-        part2(&input);
+        part1(input);
+        part2(input);
     }
+    example r#"
+        root: pppw + sjmn
+        dbpl: 5
+        cczh: sllz + lgvd
+        zczc: 2
+        ptdq: humn - dvpt
+        dvpt: 3
+        lfqf: 4
+        humn: 5
+        ljgn: 2
+        sjmn: drzm * dbpl
+        sllz: 4
+        pppw: cczh / lfqf
+        lgvd: ljgn * ptdq
+        drzm: hmdt - zczc
+        hmdt: 32
+        "# => part1 152, part2 301;
 );
 
 fn part1(ops: &Input) -> Output {
@@ -134,7 +151,7 @@ pub enum Op {
 impl PuzzleInput for Ops {
     type Out = Self;
 
-    fn from_input(input: &str) -> Self::Out {
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
         let monkeys = lines(input)
             .map(|l| {
                 let b = l.as_bytes();
@@ -157,63 +174,6 @@ impl PuzzleInput for Ops {
             })
             .collect::<FxHashMap<_, _>>();
 
-        Self { ops: monkeys }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        root: pppw + sjmn
-        dbpl: 5
-        cczh: sllz + lgvd
-        zczc: 2
-        ptdq: humn - dvpt
-        dvpt: 3
-        lfqf: 4
-        humn: 5
-        ljgn: 2
-        sjmn: drzm * dbpl
-        sllz: 4
-        pppw: cczh / lfqf
-        lgvd: ljgn * ptdq
-        drzm: hmdt - zczc
-        hmdt: 32
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 152);
-        assert_eq!(res2, 301);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 118565889858886);
-        assert_eq!(res2, 3032671800353);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
+        Ok(Self { ops: monkeys })
     }
 }
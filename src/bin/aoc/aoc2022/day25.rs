@@ -6,9 +6,24 @@ type Output = String;
 register!(
     "input/day25.txt";
     (input: input!(parse Input)) -> Output {
-        part1(&input);
-        part2(&input);
+        part1(input);
+        part2(input);
     }
+    example r#"
+        1=-0-2
+        12111
+        2=0=
+        21
+        2=01
+        111
+        20012
+        112
+        1=-1=
+        1-12
+        12
+        1=
+        122
+        "# => part1 "2=-1=0", part2 "";
 );
 
 fn part1(items: &[Input]) -> Output {
@@ -86,58 +101,3 @@ impl FromStr for Snafu {
         Ok(Self(num))
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        1=-0-2
-        12111
-        2=0=
-        21
-        2=01
-        111
-        20012
-        112
-        1=-1=
-        1-12
-        12
-        1=
-        122
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, "2=-1=0");
-        assert_eq!(res2, "");
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, "2-2=21=0021=-02-1=-0");
-        assert_eq!(res2, "");
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
-}
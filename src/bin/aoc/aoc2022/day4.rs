@@ -0,0 +1,64 @@
+use std::ops::RangeInclusive;
+
+use aoc::ranges::IntervalSet;
+
+type Input = Pair;
+type Output = usize;
+
+register!(
+    "input/day4.txt";
+    (input: input!(Input)) -> Output {
+        part1(input);
+        part2(input);
+    }
+    example r#"
+        2-4,6-8
+        2-3,4-5
+        5-7,7-9
+        2-8,3-7
+        6-6,4-6
+        2-6,4-8
+        "# => part1 2, part2 4;
+);
+
+fn part1(items: &[Input]) -> Output {
+    items.iter().filter(|p| p.cover()).count()
+}
+
+fn part2(items: &[Input]) -> Output {
+    items.iter().filter(|p| p.overlap()).count()
+}
+
+pub struct Pair(RangeInclusive<u8>, RangeInclusive<u8>);
+
+impl Pair {
+    fn sets(&self) -> (IntervalSet<u8>, IntervalSet<u8>) {
+        (self.0.clone().into(), self.1.clone().into())
+    }
+
+    fn cover(&self) -> bool {
+        let (l, r) = self.sets();
+        let common = l.intersection(&r);
+        common == l || common == r
+    }
+
+    fn overlap(&self) -> bool {
+        let (l, r) = self.sets();
+        !l.intersection(&r).is_empty()
+    }
+}
+
+impl From<&str> for Pair {
+    fn from(value: &str) -> Self {
+        let (l, r) = value.split_once(',').expect("no comma");
+        let l = l
+            .split_once('-')
+            .map(|(l, r)| l.parse::<u8>().unwrap()..=r.parse::<u8>().unwrap())
+            .expect("no dash");
+        let r = r
+            .split_once('-')
+            .map(|(l, r)| l.parse::<u8>().unwrap()..=r.parse::<u8>().unwrap())
+            .expect("no dash");
+        Self(l, r)
+    }
+}
@@ -0,0 +1,122 @@
+use std::str::FromStr;
+
+use aoc::{parse::ParseError, scan, InputError, PuzzleInput};
+
+type Input = (Stacks, Vec<Move>);
+type Output = String;
+
+register!(
+    "input/day5.txt";
+    (input: input!(sections (Crates, input!(parse Move)))) -> Output {
+        part1(input);
+        part2(input);
+    }
+    example r#"
+    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2"# => part1 "CMZ".to_string(), part2 "MCD".to_string();
+);
+
+fn part1((stacks, moves): &Input) -> Output {
+    unload(stacks.clone(), moves, CrateMover::CrateMover9000)
+}
+
+fn part2((stacks, moves): &Input) -> Output {
+    unload(stacks.clone(), moves, CrateMover::CrateMover9001)
+}
+
+fn unload(mut stacks: Stacks, moves: &[Move], crane: CrateMover) -> Output {
+    for &Move { cnt, src, tgt } in moves {
+        match crane {
+            CrateMover::CrateMover9000 => {
+                // split_off + reverse + append is slower
+                for _ in 0..cnt {
+                    let item = stacks[src].pop().unwrap();
+                    stacks[tgt].push(item);
+                }
+            }
+            CrateMover::CrateMover9001 => {
+                let src_len = stacks[src].len();
+                // split_off is O(1) as it reuses the buffer
+                // from the source vec for the new vec
+                let mut crates = stacks[src].split_off(src_len - cnt);
+                stacks[tgt].append(&mut crates);
+            }
+        }
+    }
+
+    stacks.iter().map(|s| s.last().unwrap()).collect::<String>()
+}
+
+type Stacks = Vec<Vec<char>>;
+
+#[derive(Clone, Copy)]
+pub enum CrateMover {
+    CrateMover9000,
+    CrateMover9001,
+}
+
+pub struct Crates;
+
+impl PuzzleInput for Crates {
+    type Out = Stacks;
+
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
+        let mut stacks: Stacks = vec![];
+
+        for line in input
+            .lines()
+            .filter(|l| !l.is_empty())
+            .take_while(|l| !l.trim_start().starts_with('1'))
+        {
+            // each stack takes up 4 chars
+            let line = line.as_bytes();
+            let stack_cnt = (line.len() + 1) / 4;
+            if stacks.len() < stack_cnt {
+                stacks.resize_with(stack_cnt, Vec::new);
+            }
+
+            for s in 0..stack_cnt {
+                match line[s * 4 + 1] {
+                    b' ' => continue,
+                    item => stacks[s].push(item as char),
+                }
+            }
+        }
+        // get stack content in correct order
+        stacks.iter_mut().for_each(|s| s.reverse());
+
+        Ok(stacks)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Move {
+    cnt: usize,
+    src: usize,
+    tgt: usize,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (cnt, src, tgt) = scan!(s, "move {} from {} to {}" => usize, usize, usize)?;
+        let index = |n: usize| {
+            n.checked_sub(1)
+                .ok_or_else(|| ParseError::new("stacks are numbered from 1"))
+        };
+
+        Ok(Self {
+            cnt,
+            src: index(src)?,
+            tgt: index(tgt)?,
+        })
+    }
+}
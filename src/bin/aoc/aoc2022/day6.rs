@@ -6,9 +6,12 @@ type Output = usize;
 register!(
     "input/day6.txt";
     (input: input!(first input!(parse Input))) -> Output {
-        part1(&input);
-        part2(&input);
+        part1(input);
+        part2(input);
     }
+    example r#"
+        mjqjpqmgbljsphdztnvjfqwrcgsmlb
+        "# => part1 7, part2 19;
 );
 
 fn part1(buffer: &Input) -> Output {
@@ -55,46 +58,3 @@ impl FromStr for Buffer {
         Ok(Self(s))
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        mjqjpqmgbljsphdztnvjfqwrcgsmlb
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 7);
-        assert_eq!(res2, 19);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 1912);
-        assert_eq!(res2, 2122);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
-}
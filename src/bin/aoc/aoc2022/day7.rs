@@ -1,4 +1,4 @@
-use aoc::{lines, PuzzleInput};
+use aoc::{lines, InputError, PuzzleInput};
 
 type Input = Node;
 type Output = u32;
@@ -6,9 +6,34 @@ type Output = u32;
 register!(
     "input/day7.txt";
     (input: input!(verbatim Input)) -> Output {
-        part1(&input);
-        part2(&input);
+        part1(input);
+        part2(input);
     }
+    example r#"
+        $ cd /
+        $ ls
+        dir a
+        14848514 b.txt
+        8504156 c.dat
+        dir d
+        $ cd a
+        $ ls
+        dir e
+        29116 f
+        2557 g
+        62596 h.lst
+        $ cd e
+        $ ls
+        584 i
+        $ cd ..
+        $ cd ..
+        $ cd d
+        $ ls
+        4060174 j
+        8033020 d.log
+        5626152 d.ext
+        7214296 k
+        "# => part1 95437, part2 24933642;
 );
 
 fn part1(fs: &Input) -> Output {
@@ -54,7 +79,7 @@ impl Node {
 impl PuzzleInput for Node {
     type Out = Self;
 
-    fn from_input(input: &str) -> Self::Out {
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
         fn read_dir<'a, I>(lines: &mut I) -> Node
         where
             I: Iterator<Item = &'a str>,
@@ -83,71 +108,6 @@ impl PuzzleInput for Node {
             Node { subs, size }
         }
 
-        read_dir(&mut lines(input).skip(1))
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"
-        $ cd /
-        $ ls
-        dir a
-        14848514 b.txt
-        8504156 c.dat
-        dir d
-        $ cd a
-        $ ls
-        dir e
-        29116 f
-        2557 g
-        62596 h.lst
-        $ cd e
-        $ ls
-        584 i
-        $ cd ..
-        $ cd ..
-        $ cd d
-        $ ls
-        4060174 j
-        8033020 d.log
-        5626152 d.ext
-        7214296 k
-        "#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 95437);
-        assert_eq!(res2, 24933642);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 1350966);
-        assert_eq!(res2, 6296435);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
+        Ok(read_dir(&mut lines(input).skip(1)))
     }
 }
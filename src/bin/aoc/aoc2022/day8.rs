@@ -0,0 +1,76 @@
+use aoc::grid::Grid;
+
+type Input = Grid<u8>;
+type Output = usize;
+
+register!(
+    "input/day8.txt";
+    (input: input!(grid u8)) -> Output {
+        part1(input);
+        part2(input);
+    }
+    example r#"
+        30373
+        25512
+        65332
+        33549
+        35390
+        "# => part1 21, part2 8;
+);
+
+fn part1(forest: &Input) -> Output {
+    let mut visible = Grid::filled(forest.width(), forest.height(), false);
+
+    for row in 0..forest.height() {
+        let trees = forest.row(row).iter().enumerate();
+        sweep(trees.clone(), |col| visible[(row, col)] = true);
+        sweep(trees.rev(), |col| visible[(row, col)] = true);
+    }
+    for col in 0..forest.width() {
+        sweep(forest.col(col).enumerate(), |row| {
+            visible[(row, col)] = true
+        });
+        sweep(forest.col(col).enumerate().rev(), |row| {
+            visible[(row, col)] = true
+        });
+    }
+
+    visible.cells().iter().filter(|v| **v).count()
+}
+
+/// Marks each tree that is higher than all trees before it.
+fn sweep<'a>(trees: impl Iterator<Item = (usize, &'a u8)>, mut mark: impl FnMut(usize)) {
+    // '/' < '0' <=> 47_u8 < 48_u8
+    let mut max = b'/';
+    for (i, &tree) in trees {
+        if tree > max {
+            mark(i);
+            max = tree;
+        }
+    }
+}
+
+fn part2(forest: &Input) -> Output {
+    forest
+        .iter()
+        .map(|((row, col), &tree)| {
+            let trees = forest.row(row);
+            view(trees[..col].iter().rev(), tree)
+                * view(trees[col + 1..].iter(), tree)
+                * view(forest.col(col).take(row).rev(), tree)
+                * view(forest.col(col).skip(row + 1), tree)
+        })
+        .max()
+        .unwrap_or_default()
+}
+
+fn view<'a>(trees: impl Iterator<Item = &'a u8>, height: u8) -> usize {
+    let mut view = 0;
+    for &tree in trees {
+        view += 1;
+        if tree >= height {
+            break;
+        }
+    }
+    view
+}
@@ -0,0 +1,77 @@
+use std::{convert::Infallible, str::FromStr};
+
+use aoc::geom::{Direction, Point, Point2};
+use fxhash::FxHashSet;
+
+type Input = Command;
+type Output = usize;
+
+register!(
+    "input/day9.txt";
+    (input: input!(parse Input)) -> Output {
+        part1(input);
+        part2(input);
+    }
+    example r#"
+        R 4
+        U 4
+        L 3
+        D 1
+        R 4
+        D 1
+        L 5
+        R 2
+        "# => part1 13, part2 1;
+);
+
+fn part1(commands: &[Input]) -> Output {
+    solve::<2>(commands)
+}
+
+fn part2(commands: &[Input]) -> Output {
+    solve::<10>(commands)
+}
+
+fn solve<const N: usize>(commands: &[Input]) -> Output {
+    let mut visits = FxHashSet::<Point2<i32>>::default();
+    let mut t = [Point::ORIGIN; N];
+
+    for cmd in commands {
+        let step = cmd.direction.delta();
+        for _ in 0..cmd.times {
+            t[0] += step;
+            for i in 1..N {
+                t[i] = follow(t[i - 1], t[i]);
+            }
+            visits.insert(t[N - 1]);
+        }
+    }
+
+    visits.len()
+}
+
+fn follow(h: Point2<i32>, t: Point2<i32>) -> Point2<i32> {
+    if h.chebyshev(t) <= 1 {
+        return t;
+    }
+    t + (h - t).signum()
+}
+
+#[derive(Debug)]
+pub struct Command {
+    direction: Direction,
+    times: u8,
+}
+
+impl FromStr for Command {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (direction, times) = s.split_once(' ').unwrap();
+
+        let direction = direction.parse::<Direction>().unwrap();
+        let times = times.parse::<u8>().unwrap();
+
+        Ok(Self { direction, times })
+    }
+}
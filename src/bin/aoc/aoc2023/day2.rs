@@ -0,0 +1,115 @@
+use std::str::FromStr;
+
+use aoc::parse::{field, keyword, tokens, ParseError, Token};
+
+type Input = Game;
+type Output = usize;
+
+register!(
+    "input/day2.txt";
+    (input: input!(parse Input)) -> Output {
+        part1(input);
+        part2(input);
+    }
+    example r#"
+            Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+            Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+            Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+            Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+            Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+        "# => part1 8, part2 2286;
+);
+
+fn part1(games: &[Input]) -> Output {
+    games
+        .iter()
+        .filter(|game| game.is_valid())
+        .map(|g| g.id)
+        .sum()
+}
+
+fn part2(games: &[Input]) -> Output {
+    games
+        .iter()
+        .map(|game| game.sets.iter().fold(Set::default(), Set::max).power())
+        .sum()
+}
+
+pub struct Game {
+    pub id: usize,
+    sets: Vec<Set>,
+}
+
+#[derive(Default)]
+pub struct Set {
+    red: usize,
+    green: usize,
+    blue: usize,
+}
+
+impl Set {
+    fn is_valid(&self) -> bool {
+        self.red <= 12 && self.green <= 13 && self.blue <= 14
+    }
+
+    fn power(&self) -> usize {
+        self.red * self.green * self.blue
+    }
+
+    fn max(self, other: &Self) -> Self {
+        Self {
+            red: usize::max(self.red, other.red),
+            green: usize::max(self.green, other.green),
+            blue: usize::max(self.blue, other.blue),
+        }
+    }
+}
+
+impl Game {
+    fn is_valid(&self) -> bool {
+        self.sets.iter().all(Set::is_valid)
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (id, sets) = s.split_once(':').ok_or_else(|| ParseError::new("missing `:`"))?;
+        let id = field(keyword(id, "Game")?)?;
+
+        let sets = sets
+            .split(';')
+            .map(str::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { id, sets })
+    }
+}
+
+/// Parses `3 blue, 4 red` into the number of cubes per color.
+impl FromStr for Set {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = Self::default();
+        let mut tokens = tokens(s).filter(|t| !matches!(t, Ok(Token::Symbol(','))));
+
+        while let Some(token) = tokens.next() {
+            let (Token::Int(n), Some(Token::Word(color))) = (token?, tokens.next().transpose()?)
+            else {
+                return Err(ParseError::new(format!("expected `<count> <color>` in `{s}`")));
+            };
+            let n = usize::try_from(n)
+                .map_err(|_| ParseError::new(format!("negative count `{n}` in `{s}`")))?;
+            match color {
+                "red" => set.red += n,
+                "green" => set.green += n,
+                "blue" => set.blue += n,
+                _ => return Err(ParseError::new(format!("unsupported color `{color}`"))),
+            }
+        }
+
+        Ok(set)
+    }
+}
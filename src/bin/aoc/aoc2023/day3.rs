@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use aoc::grid::Grid;
+use fxhash::FxHashMap;
+
+type Input = Grid<Cell>;
+type Output = usize;
+
+register!(
+    "input/day3.txt";
+    (input: input!(grid chars Cell)) -> Output {
+        part1(input);
+        part2(input);
+    }
+    example r#"
+            467..114..
+            ...*......
+            ..35..633.
+            ......#...
+            617*......
+            .....+.58.
+            ..592.....
+            ......755.
+            ...$.*....
+            .664.598..
+            "# => part1 4361, part2 467835;
+);
+
+fn part1(schematic: &Input) -> Output {
+    solve(schematic).0
+}
+
+fn part2(schematic: &Input) -> Output {
+    solve(schematic).1
+}
+
+fn solve(schematic: &Input) -> (usize, usize) {
+    let mut sum = 0;
+
+    let mut gears = FxHashMap::default();
+
+    for (r, row) in schematic.rows().enumerate() {
+        let mut is_adjacent = false;
+
+        let mut number = 0;
+        let mut gears_local = HashSet::new();
+
+        // the trailing blank ends numbers at the right edge
+        for (c, cell) in row.iter().chain([&Cell::Blank]).enumerate() {
+            if let Cell::Number(n) = cell {
+                number = 10 * number + n;
+
+                for pos in schematic.neighbors8((r, c)) {
+                    if let Cell::Symbol(s) = schematic[pos] {
+                        if s == '*' {
+                            gears_local.insert(pos);
+                        }
+                        is_adjacent = true;
+                    }
+                }
+            } else if number > 0 {
+                if is_adjacent {
+                    sum += number;
+                    gears_local.iter().copied().for_each(|gear| {
+                        gears
+                            .entry(gear)
+                            .and_modify(|nums: &mut Vec<u32>| nums.push(number))
+                            .or_insert_with(|| vec![number]);
+                    });
+                }
+                number = 0;
+                is_adjacent = false;
+                gears_local.clear();
+            }
+        }
+    }
+
+    let ratio = gears
+        .values()
+        .filter(|nums| nums.len() == 2)
+        .map(|nums| nums[0] * nums[1])
+        .sum::<u32>();
+
+    (sum as usize, ratio as usize)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Cell {
+    Number(u32),
+    Symbol(char),
+    Blank,
+}
+
+impl From<char> for Cell {
+    fn from(c: char) -> Self {
+        match c {
+            '.' => Self::Blank,
+            d => d.to_digit(10).map_or(Self::Symbol(d), Self::Number),
+        }
+    }
+}
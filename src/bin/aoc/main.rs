@@ -6,6 +6,7 @@
     iter_collect_into,
     iter_partition_in_place,
     iterator_try_collect,
+    slice_take,
     test
 )]
 #![warn(
@@ -22,6 +23,7 @@
     clippy::cast_sign_loss,
     clippy::missing_const_for_fn,
     clippy::redundant_pub_crate,
+    clippy::similar_names,
     unused_variables
 )]
 
@@ -31,29 +33,8 @@ extern crate aoc;
 extern crate test;
 
 aoc_main!(
-    1 => day1,
-    2 => day2,
-    3 => day3,
-    4 => day4,
-// 5 => day5,
-// 6 => day6,
-// 7 => day7,
-// 8 => day8,
-// 9 => day9,
-// 10 => day10,
-// 11 => day11,
-// 12 => day12,
-// 13 => day13,
-// 14 => day14,
-// 15 => day15,
-// 16 => day16,
-// 17 => day17,
-// 18 => day18,
-// 19 => day19,
-// 20 => day20,
-// 21 => day21,
-// 22 => day22,
-// 23 => day23,
-// 24 => day24,
-// 25 => day25,
+    2020 => aoc2020,
+    2021 => aoc2021,
+    2022 => aoc2022,
+    2023 => aoc2023,
 );
@@ -1,72 +0,0 @@
-type Input = u64;
-type Output = usize;
-
-register!(
-    "input/day1.txt";
-    (input: input!(parse Input)) -> Output {
-        part1(&input);
-        part2(&input);
-    }
-);
-
-fn part1(measurements: &[Input]) -> Output {
-    measurements.array_windows().filter(|[a, b]| a < b).count()
-}
-
-fn part2(measurements: &[Input]) -> Output {
-    measurements
-        .array_windows()
-        // (a + b + c) < (b + c + d) <=> a < d
-        .filter(|[a, _, _, d]| a < d)
-        .count()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use aoc::{Solution, SolutionExt};
-    use test::Bencher;
-
-    #[test]
-    fn test_ex() {
-        let input = r#"199
-                       200
-                       208
-                       210
-                       200
-                       207
-                       240
-                       269
-                       260
-                       263"#;
-        let (res1, res2) = Solver::run_on(input);
-        assert_eq!(res1, 7);
-        assert_eq!(res2, 5);
-    }
-
-    #[test]
-    fn test() {
-        let (res1, res2) = Solver::run_on_input();
-        assert_eq!(res1, 1316);
-        assert_eq!(res2, 1344);
-    }
-
-    #[bench]
-    fn bench_parsing(b: &mut Bencher) {
-        let input = Solver::puzzle_input();
-        b.bytes = input.len() as u64;
-        b.iter(|| Solver::parse_input(input));
-    }
-
-    #[bench]
-    fn bench_pt1(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part1(&input));
-    }
-
-    #[bench]
-    fn bench_pt2(b: &mut Bencher) {
-        let input = Solver::parse_input(Solver::puzzle_input());
-        b.iter(|| part2(&input));
-    }
-}
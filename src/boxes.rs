@@ -0,0 +1,198 @@
+/// An axis-aligned, half-open box of integer points in `N` dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<const N: usize> {
+    pub min: [i64; N],
+    pub max: [i64; N],
+}
+
+impl<const N: usize> Cuboid<N> {
+    pub fn new(min: [i64; N], max: [i64; N]) -> Self {
+        Self { min, max }
+    }
+
+    /// A box containing both `lo` and `hi`, as in `x=10..12` puzzle inputs.
+    pub fn inclusive(lo: [i64; N], hi: [i64; N]) -> Self {
+        Self::new(lo, hi.map(|c| c + 1))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..N).any(|i| self.min[i] >= self.max[i])
+    }
+
+    pub fn volume(&self) -> u64 {
+        (0..N)
+            .map(|i| {
+                self.max[i]
+                    .saturating_sub(self.min[i])
+                    .max(0)
+                    .unsigned_abs()
+            })
+            .product()
+    }
+
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        (0..N).all(|i| self.min[i] <= point[i] && point[i] < self.max[i])
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut common = *self;
+        for i in 0..N {
+            common.min[i] = self.min[i].max(other.min[i]);
+            common.max[i] = self.max[i].min(other.max[i]);
+        }
+        (!common.is_empty()).then_some(common)
+    }
+
+    /// Splits off the parts of `self` outside of `other`,
+    /// resulting in at most `2 * N` disjoint boxes.
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let Some(common) = self.intersection(other) else {
+            return vec![*self];
+        };
+
+        let mut pieces = Vec::with_capacity(2 * N);
+        let mut rest = *self;
+
+        for i in 0..N {
+            if rest.min[i] < common.min[i] {
+                let mut below = rest;
+                below.max[i] = common.min[i];
+                pieces.push(below);
+            }
+            if common.max[i] < rest.max[i] {
+                let mut above = rest;
+                above.min[i] = common.max[i];
+                pieces.push(above);
+            }
+            rest.min[i] = common.min[i];
+            rest.max[i] = common.max[i];
+        }
+
+        pieces
+    }
+}
+
+/// A union of disjoint [`Cuboid`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoxSet<const N: usize> {
+    boxes: Vec<Cuboid<N>>,
+}
+
+impl<const N: usize> Default for BoxSet<N> {
+    fn default() -> Self {
+        Self { boxes: Vec::new() }
+    }
+}
+
+impl<const N: usize> BoxSet<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn boxes(&self) -> &[Cuboid<N>] {
+        &self.boxes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.boxes.is_empty()
+    }
+
+    /// The number of integer points in the set.
+    pub fn volume(&self) -> u64 {
+        self.boxes.iter().map(Cuboid::volume).sum()
+    }
+
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        self.boxes.iter().any(|b| b.contains(point))
+    }
+
+    pub fn insert(&mut self, cuboid: Cuboid<N>) {
+        if cuboid.is_empty() {
+            return;
+        }
+        let mut pieces = vec![cuboid];
+        for b in &self.boxes {
+            pieces = pieces.iter().flat_map(|piece| piece.subtract(b)).collect();
+        }
+        self.boxes.extend(pieces);
+    }
+
+    pub fn remove(&mut self, cuboid: &Cuboid<N>) {
+        self.boxes = self.boxes.iter().flat_map(|b| b.subtract(cuboid)).collect();
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        other.boxes.iter().for_each(|b| union.insert(*b));
+        union
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        other.boxes.iter().for_each(|b| difference.remove(b));
+        difference
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        // intersections of two disjoint sets are disjoint as well
+        let boxes = self
+            .boxes
+            .iter()
+            .flat_map(|a| other.boxes.iter().filter_map(|b| a.intersection(b)))
+            .collect();
+        Self { boxes }
+    }
+}
+
+impl<const N: usize> From<Cuboid<N>> for BoxSet<N> {
+    fn from(cuboid: Cuboid<N>) -> Self {
+        Self::from_iter([cuboid])
+    }
+}
+
+impl<const N: usize> FromIterator<Cuboid<N>> for BoxSet<N> {
+    fn from_iter<I: IntoIterator<Item = Cuboid<N>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        iter.into_iter().for_each(|c| set.insert(c));
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cuboid() {
+        let a = Cuboid::inclusive([0, 0, 0], [2, 2, 2]);
+        let b = Cuboid::new([1, 1, 1], [5, 5, 5]);
+
+        assert_eq!(a.volume(), 27);
+        assert!(a.contains([2, 2, 2]) && !a.contains([3, 0, 0]));
+        assert_eq!(a.intersection(&b), Some(Cuboid::new([1, 1, 1], [3, 3, 3])));
+        assert_eq!(a.intersection(&Cuboid::new([3, 0, 0], [4, 1, 1])), None);
+
+        let pieces = a.subtract(&b);
+        assert_eq!(pieces.len(), 3);
+        assert_eq!(pieces.iter().map(Cuboid::volume).sum::<u64>(), 27 - 8);
+        assert!(pieces.iter().all(|p| p.intersection(&b).is_none()));
+    }
+
+    #[test]
+    fn test_box_set() {
+        let a = BoxSet::from(Cuboid::new([0, 0], [4, 4]));
+        let b = BoxSet::from(Cuboid::new([2, 2], [6, 6]));
+
+        assert_eq!(a.union(&b).volume(), 28);
+        assert_eq!(a.intersection(&b).volume(), 4);
+        assert_eq!(a.difference(&b).volume(), 12);
+        assert!(a.difference(&b).contains([1, 3]));
+        assert!(!a.difference(&b).contains([3, 3]));
+
+        let mut set = a.union(&b);
+        set.remove(&Cuboid::new([-10, -10], [10, 3]));
+        assert_eq!(set.volume(), 4 + 12 - 2);
+        set.insert(Cuboid::new([0, 0], [0, 10]));
+        assert_eq!(set.volume(), 14);
+    }
+}
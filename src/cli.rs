@@ -0,0 +1,757 @@
+use fxhash::FxHashSet;
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    fs, io,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    animate::{self, Viewport},
+    baseline::DEFAULT_THRESHOLD,
+    log::{self, Level},
+    report::Format,
+    viz,
+};
+
+const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug)]
+pub struct ArgsError(String);
+
+impl Display for ArgsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ArgsError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Years {
+    Latest,
+    All,
+    Year(u16),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Days {
+    All,
+    Range(RangeInclusive<u8>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Selection {
+    years: Years,
+    days: Vec<Days>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct InputKey {
+    year: Option<u16>,
+    day: Option<u8>,
+}
+
+impl InputKey {
+    fn matches(self, year: u16, day: u8) -> bool {
+        self.year.is_none_or(|y| y == year) && self.day.is_none_or(|d| d == day)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Args {
+    selection: Vec<Selection>,
+    pub part: Option<Part>,
+    pub list: bool,
+    pub quiet: bool,
+    pub verify: bool,
+    pub bench: Option<usize>,
+    pub baseline: Option<PathBuf>,
+    pub save_baseline: Option<PathBuf>,
+    threshold: Option<f64>,
+    pub help: bool,
+    pub format: Format,
+    inputs: Vec<(InputKey, PathBuf)>,
+    input_dir: Option<PathBuf>,
+    pub record: Option<PathBuf>,
+    pub record_format: viz::Format,
+    pub animate: bool,
+    fps: Option<f64>,
+    viewport: Option<Viewport>,
+    verbosity: u8,
+    pub log_targets: Vec<log::Target>,
+}
+
+impl Args {
+    pub fn from_env() -> Result<Self, ArgsError> {
+        Self::parse(std::env::args().skip(1))
+    }
+
+    pub fn parse<I, S>(args: I) -> Result<Self, ArgsError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut args = args.into_iter().map(Into::into);
+        let mut res = Self::default();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => res.help = true,
+                "-l" | "--list" => res.list = true,
+                "-q" | "--quiet" => res.quiet = true,
+                "--verify" => res.verify = true,
+                "-v" | "--verbose" => res.verbosity += 1,
+                "-vv" => res.verbosity += 2,
+                "--log" => {
+                    let value = value(&mut args, &arg)?;
+                    res.log_targets.push(
+                        value
+                            .parse()
+                            .map_err(|e: log::LogError| ArgsError(e.to_string()))?,
+                    );
+                }
+                "--bench" => {
+                    let value = value(&mut args, &arg)?;
+                    let runs = value.parse::<usize>().ok().filter(|&runs| runs > 0);
+                    res.bench = Some(runs.ok_or_else(|| {
+                        ArgsError(format!(
+                            "invalid number of runs `{value}`, expected a positive number"
+                        ))
+                    })?);
+                }
+                "--baseline" => {
+                    let value = value(&mut args, &arg)?;
+                    res.baseline = Some(PathBuf::from(value));
+                }
+                "--save-baseline" => {
+                    let value = value(&mut args, &arg)?;
+                    res.save_baseline = Some(PathBuf::from(value));
+                }
+                "--threshold" => {
+                    let value = value(&mut args, &arg)?;
+                    let threshold = value.parse::<f64>().ok().filter(|t| *t >= 0.0);
+                    res.threshold = Some(threshold.ok_or_else(|| {
+                        ArgsError(format!(
+                            "invalid threshold `{value}`, expected a percentage like `5` or `2.5`"
+                        ))
+                    })?);
+                }
+                "-p" | "--part" => {
+                    let value = value(&mut args, &arg)?;
+                    res.part = Some(match value.as_str() {
+                        "1" => Part::One,
+                        "2" => Part::Two,
+                        _ => {
+                            return Err(ArgsError(format!(
+                                "invalid part `{value}`, expected 1 or 2"
+                            )))
+                        }
+                    });
+                }
+                "-f" | "--format" => {
+                    let value = value(&mut args, &arg)?;
+                    res.format = match value.as_str() {
+                        "table" => Format::Table,
+                        "json" => Format::Json,
+                        "csv" => Format::Csv,
+                        _ => {
+                            return Err(ArgsError(format!(
+                                "invalid format `{value}`, expected table, json or csv"
+                            )))
+                        }
+                    };
+                }
+                "-y" | "--year" => {
+                    let value = value(&mut args, &arg)?;
+                    let years = match value.as_str() {
+                        "all" => Years::All,
+                        year => Years::Year(self::year(year).ok_or_else(|| {
+                            ArgsError(format!(
+                                "invalid year `{value}`, expected e.g. `2022` or `all`"
+                            ))
+                        })?),
+                    };
+                    res.selection.push(Selection {
+                        years,
+                        days: vec![],
+                    });
+                }
+                "-i" | "--input" => {
+                    let value = value(&mut args, &arg)?;
+                    res.inputs.push(input_arg(&value));
+                }
+                "--input-dir" => {
+                    let value = value(&mut args, &arg)?;
+                    res.input_dir = Some(PathBuf::from(value));
+                }
+                "--record" => {
+                    let value = value(&mut args, &arg)?;
+                    res.record = Some(PathBuf::from(value));
+                }
+                "--record-format" => {
+                    let value = value(&mut args, &arg)?;
+                    res.record_format = match value.as_str() {
+                        "ppm" => viz::Format::Ppm,
+                        "png" => viz::Format::Png,
+                        "gif" => viz::Format::Gif,
+                        _ => {
+                            return Err(ArgsError(format!(
+                                "invalid record format `{value}`, expected ppm, png or gif"
+                            )))
+                        }
+                    };
+                }
+                "--animate" => res.animate = true,
+                "--fps" => {
+                    let value = value(&mut args, &arg)?;
+                    let fps = value.parse::<f64>().ok().filter(|fps| *fps > 0.0);
+                    res.fps = Some(fps.ok_or_else(|| {
+                        ArgsError(format!(
+                            "invalid frame rate `{value}`, expected a positive number"
+                        ))
+                    })?);
+                }
+                "--viewport" => {
+                    let value = value(&mut args, &arg)?;
+                    res.viewport = Some(
+                        value
+                            .parse()
+                            .map_err(|e: animate::ViewportError| ArgsError(e.to_string()))?,
+                    );
+                }
+                flag if flag.starts_with('-') => {
+                    return Err(ArgsError(format!("unknown option `{flag}`")));
+                }
+                arg => {
+                    if let Some(year) = year(arg) {
+                        res.selection.push(Selection {
+                            years: Years::Year(year),
+                            days: vec![],
+                        });
+                    } else {
+                        let days = days(arg)?;
+                        match res.selection.last_mut() {
+                            Some(selection) => selection.days.push(days),
+                            None => res.selection.push(Selection {
+                                years: Years::Latest,
+                                days: vec![days],
+                            }),
+                        }
+                    }
+                }
+            }
+        }
+
+        if res.animate && res.bench.is_some() {
+            return Err(ArgsError(
+                "`--animate` cannot be combined with `--bench`".to_string(),
+            ));
+        }
+        if res.record.is_some() && res.bench.is_some() {
+            return Err(ArgsError(
+                "`--record` cannot be combined with `--bench`".to_string(),
+            ));
+        }
+
+        Ok(res)
+    }
+
+    /// Resolves the selected puzzles against the registered ones.
+    ///
+    /// Days without a year refer to the latest registered year. Puzzles
+    /// are returned in the order they were requested, duplicates are
+    /// removed.
+    pub fn puzzles(&self, available: &[(u16, u8)]) -> Result<Vec<(u16, u8)>, ArgsError> {
+        if self.selection.is_empty() {
+            return Err(ArgsError(format!(
+                "no days selected, available days: {}",
+                Available(available)
+            )));
+        }
+
+        let mut years = available.iter().map(|(year, _)| *year).collect::<Vec<_>>();
+        years.dedup();
+
+        let mut puzzles = Vec::new();
+
+        for selection in &self.selection {
+            let selected_years = match selection.years {
+                Years::All => years.clone(),
+                Years::Latest => years.last().copied().into_iter().collect(),
+                Years::Year(year) if years.contains(&year) => vec![year],
+                Years::Year(year) => {
+                    return Err(ArgsError(format!(
+                        "year {year} is not implemented, available days: {}",
+                        Available(available)
+                    )));
+                }
+            };
+
+            for year in selected_years {
+                let days = available
+                    .iter()
+                    .filter(|(y, _)| *y == year)
+                    .map(|(_, day)| *day)
+                    .collect::<Vec<_>>();
+
+                if selection.days.is_empty() {
+                    puzzles.extend(days.iter().map(|&day| (year, day)));
+                }
+
+                for selected in &selection.days {
+                    match selected {
+                        Days::All => puzzles.extend(days.iter().map(|&day| (year, day))),
+                        // Skip missing days if all years are selected,
+                        // e.g. to run day 25 of every year.
+                        Days::Range(range) if selection.years == Years::All => puzzles.extend(
+                            range
+                                .clone()
+                                .filter(|day| days.contains(day))
+                                .map(|day| (year, day)),
+                        ),
+                        Days::Range(range) => {
+                            let missing = range
+                                .clone()
+                                .filter(|day| !days.contains(day))
+                                .collect::<Vec<_>>();
+
+                            if !missing.is_empty() {
+                                return Err(ArgsError(format!(
+                                    "{year} {} not implemented, available days: {}",
+                                    match missing.as_slice() {
+                                        [day] => format!("day {day} is"),
+                                        _ => format!("days {} are", DayList(&missing)),
+                                    },
+                                    Available(available)
+                                )));
+                            }
+
+                            puzzles.extend(range.clone().map(|day| (year, day)));
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut seen = FxHashSet::default();
+        puzzles.retain(|puzzle| seen.insert(*puzzle));
+
+        if puzzles.len() != 1 && self.inputs.iter().any(|(key, _)| key.day.is_none()) {
+            return Err(ArgsError(
+                "`--input <path>` requires exactly one day, use `--input <day>=<path>` instead"
+                    .to_string(),
+            ));
+        }
+
+        Ok(puzzles)
+    }
+
+    /// Percentage by which a day may be slower than the baseline.
+    pub fn threshold(&self) -> f64 {
+        self.threshold.unwrap_or(DEFAULT_THRESHOLD)
+    }
+
+    /// The most detailed log level to show, if logging was asked for.
+    pub fn log_level(&self) -> Option<Level> {
+        (self.verbosity > 0 || !self.log_targets.is_empty())
+            .then(|| Level::from_verbosity(self.verbosity))
+    }
+
+    pub fn animation(&self) -> animate::Options {
+        animate::Options {
+            fps: self.fps.unwrap_or(animate::DEFAULT_FPS),
+            viewport: self.viewport,
+        }
+    }
+
+    /// Resolves the puzzle input for the given day.
+    ///
+    /// An explicit `--input` wins over `--input-dir`, which in turn
+    /// wins over the input that is embedded into the binary. Inputs
+    /// are looked up in `<dir>/<year>/` first and in `<dir>/` second.
+    /// Days without a matching file in the input directory fall back
+    /// to the embedded input.
+    pub fn input(
+        &self,
+        year: u16,
+        day: u8,
+        file: &str,
+        embedded: &'static str,
+    ) -> io::Result<Cow<'static, str>> {
+        let explicit = self
+            .inputs
+            .iter()
+            .rev()
+            .find(|(key, _)| key.matches(year, day));
+
+        if let Some((_, path)) = explicit {
+            return read(path).map(Cow::Owned);
+        }
+
+        if let Some(dir) = &self.input_dir {
+            let file = Path::new(file).file_name().unwrap_or_default();
+            let paths = [dir.join(year.to_string()).join(file), dir.join(file)];
+
+            if let Some(path) = paths.iter().find(|path| path.is_file()) {
+                return read(path).map(Cow::Owned);
+            }
+        }
+
+        Ok(Cow::Borrowed(embedded))
+    }
+
+    /// Reads the answers that belong to inputs from `--input-dir`, if present.
+    pub fn answers(&self, year: u16) -> io::Result<Option<String>> {
+        let Some(dir) = &self.input_dir else {
+            return Ok(None);
+        };
+
+        let paths = [
+            dir.join(year.to_string()).join(ANSWERS_FILE),
+            dir.join(ANSWERS_FILE),
+        ];
+
+        paths
+            .iter()
+            .find(|path| path.is_file())
+            .map(|path| read(path))
+            .transpose()
+    }
+
+    pub fn usage(bin: &str) -> String {
+        format!(
+            "\
+Usage: {bin} [OPTIONS] [YEAR] <DAYS>...
+
+Arguments:
+  [YEAR]                    Year of the following days, defaults to the latest year
+  <DAYS>...                 Days to run, e.g. `3`, `1-5` or `all`
+
+Options:
+  -y, --year <YEAR>         Year of the following days, e.g. `2022` or `all`
+  -p, --part <PART>         Only run part 1 or 2
+  -f, --format <FORMAT>     Output format, one of `table` (default), `json` or `csv`
+  -i, --input [[<YEAR>/]<DAY>=]<PATH>
+                            Read the input for a day from a file
+      --input-dir <DIR>     Read inputs from `<DIR>/<YEAR>/` or `<DIR>/`, falling
+                            back to the embedded input for missing files
+  -l, --list                List the available days
+      --bench <RUNS>        Run each day RUNS times after a warmup and report
+                            min/median/mean/stddev, other timings are medians
+      --save-baseline <FILE>
+                            Store the timings of this run in FILE
+      --baseline <FILE>     Compare timings to FILE and fail on regressions
+      --threshold <PERCENT> Allowed slowdown compared to the baseline, defaults to 10
+      --verify              Fail if an answer differs from `input/answers.toml`
+      --record <DIR>        Write frames of simulations to DIR, requires the
+                            `viz` feature
+      --record-format <FORMAT>
+                            Format of recorded frames, one of `png` (default),
+                            `ppm` or `gif`
+      --animate             Play simulations in the terminal, requires the
+                            `viz` feature
+      --fps <FPS>           Frames per second when animating, defaults to 10
+      --viewport <WxH[+X+Y]>
+                            Only show part of the frames when animating
+  -v, --verbose             Show debug messages of solutions, `-vv` also shows
+                            trace messages, requires a debug build or the `log`
+                            feature
+      --log <YEAR>[/<DAY>]  Only show messages of the given year or day, implies
+                            `-v`
+  -q, --quiet               Do not print any results
+  -h, --help                Print this help"
+        )
+    }
+}
+
+/// Formats sorted `(year, day)` pairs as one [`DayList`] per year.
+pub struct Available<'a>(pub &'a [(u16, u8)]);
+
+impl Display for Available<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, puzzles) in self.0.chunk_by(|a, b| a.0 == b.0).enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            let days = puzzles.iter().map(|(_, day)| *day).collect::<Vec<_>>();
+            write!(f, "{}: {}", puzzles[0].0, DayList(&days))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats a sorted list of days, collapsing consecutive days into ranges.
+pub struct DayList<'a>(pub &'a [u8]);
+
+impl Display for DayList<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut days = self.0.iter().copied().peekable();
+        let mut first = true;
+
+        while let Some(start) = days.next() {
+            let mut end = start;
+            while days.next_if(|&next| next == end + 1).is_some() {
+                end += 1;
+            }
+
+            if !first {
+                f.write_str(", ")?;
+            }
+            first = false;
+
+            if start == end {
+                write!(f, "{start}")?;
+            } else {
+                write!(f, "{start}-{end}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn year(arg: &str) -> Option<u16> {
+    arg.parse::<u16>().ok().filter(|year| *year >= 2015)
+}
+
+fn days(arg: &str) -> Result<Days, ArgsError> {
+    let day = |s: &str| {
+        s.parse::<u8>().map_err(|_| {
+            ArgsError(format!(
+                "invalid day `{arg}`, expected e.g. `3`, `1-5` or `all`"
+            ))
+        })
+    };
+
+    if arg == "all" {
+        return Ok(Days::All);
+    }
+
+    match arg.split_once('-') {
+        Some((start, end)) => {
+            let (start, end) = (day(start)?, day(end)?);
+            if start > end {
+                return Err(ArgsError(format!("invalid day range `{arg}`")));
+            }
+            Ok(Days::Range(start..=end))
+        }
+        None => day(arg).map(|day| Days::Range(day..=day)),
+    }
+}
+
+fn value<I>(args: &mut I, flag: &str) -> Result<String, ArgsError>
+where
+    I: Iterator<Item = String>,
+{
+    args.next()
+        .ok_or_else(|| ArgsError(format!("`{flag}` requires a value")))
+}
+
+fn input_arg(value: &str) -> (InputKey, PathBuf) {
+    let key = |key: &str| match key.split_once('/') {
+        Some((year, day)) => Some(InputKey {
+            year: Some(self::year(year)?),
+            day: Some(day.parse().ok()?),
+        }),
+        None => Some(InputKey {
+            year: None,
+            day: Some(key.parse().ok()?),
+        }),
+    };
+
+    match value
+        .split_once('=')
+        .and_then(|(k, path)| Some((key(k)?, path)))
+    {
+        Some((key, path)) => (key, PathBuf::from(path)),
+        None => (InputKey::default(), PathBuf::from(value)),
+    }
+}
+
+fn read(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AVAILABLE: [(u16, u8); 8] = [
+        (2021, 1),
+        (2021, 2),
+        (2021, 25),
+        (2022, 1),
+        (2022, 2),
+        (2022, 3),
+        (2022, 4),
+        (2022, 7),
+    ];
+
+    fn parse(args: &str) -> Result<Args, ArgsError> {
+        Args::parse(args.split_ascii_whitespace())
+    }
+
+    fn puzzles(args: &str) -> Result<Vec<(u16, u8)>, ArgsError> {
+        parse(args)?.puzzles(&AVAILABLE)
+    }
+
+    #[test]
+    fn test_days() {
+        assert_eq!(puzzles("3").unwrap(), [(2022, 3)]);
+        assert_eq!(
+            puzzles("2-4 1").unwrap(),
+            [(2022, 2), (2022, 3), (2022, 4), (2022, 1)]
+        );
+        assert_eq!(puzzles("all").unwrap(), AVAILABLE[3..]);
+        assert_eq!(
+            puzzles("7 all").unwrap(),
+            [(2022, 7), (2022, 1), (2022, 2), (2022, 3), (2022, 4)]
+        );
+
+        assert_eq!(
+            puzzles("5-7").unwrap_err().to_string(),
+            "2022 days 5-6 are not implemented, available days: 2021: 1-2, 25; 2022: 1-4, 7"
+        );
+        assert_eq!(
+            puzzles("").unwrap_err().to_string(),
+            "no days selected, available days: 2021: 1-2, 25; 2022: 1-4, 7"
+        );
+    }
+
+    #[test]
+    fn test_years() {
+        assert_eq!(puzzles("2021 25").unwrap(), [(2021, 25)]);
+        assert_eq!(puzzles("2021").unwrap(), AVAILABLE[..3]);
+        assert_eq!(puzzles("--year all").unwrap(), AVAILABLE);
+        assert_eq!(
+            puzzles("--year all 1 25").unwrap(),
+            [(2021, 1), (2021, 25), (2022, 1)]
+        );
+        assert_eq!(puzzles("2021 1 2022 2").unwrap(), [(2021, 1), (2022, 2)]);
+        assert_eq!(
+            puzzles("-y 2022 1 -y 2021 1").unwrap(),
+            [(2022, 1), (2021, 1)]
+        );
+
+        assert!(puzzles("2020 1").is_err());
+        assert!(puzzles("2021 3").is_err());
+        assert!(puzzles("--year 1").is_err());
+    }
+
+    #[test]
+    fn test_options() {
+        let args = parse("-q --verify --part 2 3").unwrap();
+        assert!(args.quiet);
+        assert!(args.verify);
+        assert_eq!(args.part, Some(Part::Two));
+
+        assert!(parse("--part 3 1").is_err());
+        assert!(parse("--frobnicate 1").is_err());
+        assert!(parse("one").is_err());
+        assert!(parse("5-3").is_err());
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(parse("1").unwrap().format, Format::Table);
+        assert_eq!(parse("-f json 1").unwrap().format, Format::Json);
+        assert_eq!(parse("--format csv 1").unwrap().format, Format::Csv);
+        assert!(parse("--format xml 1").is_err());
+    }
+
+    #[test]
+    fn test_bench() {
+        assert_eq!(parse("1").unwrap().bench, None);
+        assert_eq!(parse("--bench 100 1").unwrap().bench, Some(100));
+        assert!(parse("--bench 0 1").is_err());
+        assert!(parse("--bench many 1").is_err());
+    }
+
+    #[test]
+    fn test_baseline() {
+        let args = parse("--baseline a.toml --save-baseline b.toml 1").unwrap();
+        assert_eq!(args.baseline, Some(PathBuf::from("a.toml")));
+        assert_eq!(args.save_baseline, Some(PathBuf::from("b.toml")));
+        assert_eq!(args.threshold(), 10.0);
+        assert_eq!(parse("--threshold 2.5 1").unwrap().threshold(), 2.5);
+        assert!(parse("--threshold -1 1").is_err());
+    }
+
+    #[test]
+    fn test_record() {
+        assert_eq!(parse("1").unwrap().record, None);
+        let args = parse("--record out --record-format gif 1").unwrap();
+        assert_eq!(args.record, Some(PathBuf::from("out")));
+        assert_eq!(args.record_format, viz::Format::Gif);
+        assert!(parse("--record-format bmp 1").is_err());
+        assert!(parse("--record out --bench 10 1").is_err());
+    }
+
+    #[test]
+    fn test_animate() {
+        assert!(!parse("1").unwrap().animate);
+        assert_eq!(
+            parse("--animate 1").unwrap().animation(),
+            animate::Options::default()
+        );
+        let args = parse("--animate --fps 2.5 --viewport 40x20+5+10 1").unwrap();
+        assert_eq!(args.animation().fps, 2.5);
+        assert_eq!(
+            args.animation().viewport.map(|v| (v.x, v.width)),
+            Some((5, 40))
+        );
+        assert!(parse("--fps 0 1").is_err());
+        assert!(parse("--viewport 40 1").is_err());
+        assert!(parse("--animate --bench 10 1").is_err());
+    }
+
+    #[test]
+    fn test_log() {
+        assert_eq!(parse("1").unwrap().log_level(), None);
+        assert_eq!(parse("-v 1").unwrap().log_level(), Some(Level::Debug));
+        assert_eq!(parse("-vv 1").unwrap().log_level(), Some(Level::Trace));
+        let args = parse("--log 2022/16 --log 2021 1").unwrap();
+        assert_eq!(args.log_level(), Some(Level::Debug));
+        assert_eq!(args.log_targets.len(), 2);
+        assert!(parse("--log 16 1").is_err());
+    }
+
+    #[test]
+    fn test_inputs() {
+        let args = parse("--input 2021/1=foo.txt --input 2=bar.txt all").unwrap();
+        assert_eq!(
+            args.inputs,
+            [
+                (
+                    InputKey {
+                        year: Some(2021),
+                        day: Some(1)
+                    },
+                    PathBuf::from("foo.txt")
+                ),
+                (
+                    InputKey {
+                        year: None,
+                        day: Some(2)
+                    },
+                    PathBuf::from("bar.txt")
+                ),
+            ]
+        );
+        assert!(args.puzzles(&AVAILABLE).is_ok());
+
+        assert!(puzzles("--input foo.txt 1 2").is_err());
+        assert!(puzzles("--input foo.txt 1").is_ok());
+        assert!(puzzles("--input a=b.txt 2021").is_err());
+    }
+}
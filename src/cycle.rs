@@ -0,0 +1,100 @@
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, Mul, Sub},
+};
+
+use fxhash::FxHashMap;
+
+/// A cycle in a simulation, found by remembering the step at which
+/// every state key was first seen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<M> {
+    /// The first step that is part of the cycle.
+    pub start: usize,
+    /// The number of steps until the state repeats.
+    pub len: usize,
+    /// The metric after each step up to and including `start + len`.
+    metrics: Vec<M>,
+}
+
+/// Steps `state` until its key repeats, recording `metric` after every step.
+///
+/// Runs forever if the simulation never repeats.
+pub fn find<S, K, M, F, G, H>(mut state: S, mut step: F, mut key: G, mut metric: H) -> Cycle<M>
+where
+    K: Eq + Hash,
+    F: FnMut(&mut S),
+    G: FnMut(&S) -> K,
+    H: FnMut(&S) -> M,
+{
+    let mut seen = FxHashMap::default();
+    let mut metrics = Vec::new();
+
+    loop {
+        let i = metrics.len();
+        metrics.push(metric(&state));
+        if let Some(start) = seen.insert(key(&state), i) {
+            return Cycle {
+                start,
+                len: i - start,
+                metrics,
+            };
+        }
+        step(&mut state);
+    }
+}
+
+impl<M> Cycle<M> {
+    /// The earliest step that ends in the same state as `step`.
+    pub fn equivalent(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.len
+        }
+    }
+
+    /// The metric after `step` steps, assuming it grows by the
+    /// same amount during every repetition of the cycle.
+    pub fn extrapolate(&self, step: usize) -> M
+    where
+        M: Copy + Add<Output = M> + Sub<Output = M> + Mul<Output = M> + TryFrom<usize>,
+        <M as TryFrom<usize>>::Error: Debug,
+    {
+        if step < self.metrics.len() {
+            return self.metrics[step];
+        }
+        let delta = self.metrics[self.start + self.len] - self.metrics[self.start];
+        let reps = M::try_from((step - self.start) / self.len).expect("too many repetitions");
+
+        self.metrics[self.equivalent(step)] + delta * reps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle() {
+        // 0, 1, 2, 3, 4, 2, 3, 4, ...
+        let step = |n: &mut (u64, u64)| {
+            n.0 = if n.0 == 4 { 2 } else { n.0 + 1 };
+            n.1 += n.0;
+        };
+        let cycle = find((0, 0), step, |n| n.0, |n| n.1);
+
+        assert_eq!((cycle.start, cycle.len), (2, 3));
+        assert_eq!(cycle.equivalent(1), 1);
+        assert_eq!(cycle.equivalent(8), 2);
+        assert_eq!(cycle.equivalent(1_000_000_000_000), 4);
+
+        let mut state = (0, 0);
+        for n in 0..20 {
+            assert_eq!(cycle.extrapolate(n), state.1, "step {n}");
+            step(&mut state);
+        }
+        assert_eq!(cycle.extrapolate(1_000_000_000_000), 2_999_999_999_998);
+    }
+}
@@ -0,0 +1,500 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    iter::Sum,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+#[derive(Debug)]
+pub struct GeomError(String);
+
+impl Display for GeomError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for GeomError {}
+
+/// A signed integer that can be used as a coordinate.
+pub trait Coord:
+    Copy
+    + Ord
+    + Hash
+    + Debug
+    + Display
+    + FromStr
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + Sum
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+
+    fn signum(self) -> Self;
+}
+
+macro_rules! coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                #[inline]
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                #[inline]
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+coord!(i8, i16, i32, i64, i128, isize);
+
+/// A point, or vector, in `N`-dimensional space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<const N: usize, T = i64>(pub [T; N]);
+
+pub type Point2<T = i64> = Point<2, T>;
+pub type Point3<T = i64> = Point<3, T>;
+
+impl<const N: usize, T: Coord> Point<N, T> {
+    pub const ORIGIN: Self = Self([T::ZERO; N]);
+
+    pub const fn new(coords: [T; N]) -> Self {
+        Self(coords)
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        (self - other).0.into_iter().map(T::abs).sum()
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        (self - other)
+            .0
+            .into_iter()
+            .map(T::abs)
+            .max()
+            .unwrap_or(T::ZERO)
+    }
+
+    /// Clamps every coordinate to `-1`, `0` or `1`.
+    pub fn signum(self) -> Self {
+        Self(self.0.map(T::signum))
+    }
+
+    /// The `2 * N` points that differ by one in a single coordinate.
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        (0..N).flat_map(move |i| {
+            [-T::ONE, T::ONE].map(|delta| {
+                let mut next = self;
+                next.0[i] += delta;
+                next
+            })
+        })
+    }
+
+    /// All `3^N - 1` points within a Chebyshev distance of one.
+    pub fn adjacent(self) -> impl Iterator<Item = Self> {
+        let count = 3_usize.pow(N as u32);
+        (0..count)
+            .filter(move |&i| i != count / 2)
+            .map(move |mut i| {
+                let mut next = self;
+                for coord in &mut next.0 {
+                    *coord += [-T::ONE, T::ZERO, T::ONE][i % 3];
+                    i /= 3;
+                }
+                next
+            })
+    }
+
+    /// The smallest and largest corner of the box containing all `points`.
+    pub fn bounds<I>(points: I) -> Option<(Self, Self)>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        points.into_iter().fold(None, |bounds, p| {
+            let (mut lo, mut hi) = bounds.unwrap_or((p, p));
+            for i in 0..N {
+                lo.0[i] = lo.0[i].min(p.0[i]);
+                hi.0[i] = hi.0[i].max(p.0[i]);
+            }
+            Some((lo, hi))
+        })
+    }
+
+    /// Whether the point lies inside the box from `lo` to `hi`, inclusive.
+    pub fn within(self, lo: Self, hi: Self) -> bool {
+        (0..N).all(|i| lo.0[i] <= self.0[i] && self.0[i] <= hi.0[i])
+    }
+}
+
+impl<T: Copy> Point<2, T> {
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+}
+
+impl<T: Copy> Point<3, T> {
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> T {
+        self.0[2]
+    }
+}
+
+impl<const N: usize, T> From<[T; N]> for Point<N, T> {
+    fn from(coords: [T; N]) -> Self {
+        Self(coords)
+    }
+}
+
+impl<const N: usize, T> Index<usize> for Point<N, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<const N: usize, T> IndexMut<usize> for Point<N, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<const N: usize, T: Coord> Add for Point<N, T> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<const N: usize, T: Coord> AddAssign for Point<N, T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0.iter_mut().zip(rhs.0).for_each(|(a, b)| *a += b);
+    }
+}
+
+impl<const N: usize, T: Coord> Sub for Point<N, T> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const N: usize, T: Coord> SubAssign for Point<N, T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0.iter_mut().zip(rhs.0).for_each(|(a, b)| *a -= b);
+    }
+}
+
+impl<const N: usize, T: Coord> Neg for Point<N, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.map(T::neg))
+    }
+}
+
+impl<const N: usize, T: Coord> Mul<T> for Point<N, T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self(self.0.map(|c| c * rhs))
+    }
+}
+
+impl<const N: usize, T: Coord> Sum for Point<N, T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ORIGIN, Add::add)
+    }
+}
+
+impl<const N: usize, T: Display> Display for Point<N, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, c) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{c}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses comma separated coordinates like `3,-4` or `1, 2, 3`.
+impl<const N: usize, T: Coord> FromStr for Point<N, T> {
+    type Err = GeomError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut point = Self::ORIGIN;
+        let mut coords = s.split(',');
+
+        for coord in &mut point.0 {
+            let c = coords
+                .next()
+                .ok_or_else(|| GeomError(format!("expected {N} coordinates in `{s}`")))?;
+            *coord = c
+                .trim()
+                .parse()
+                .map_err(|_| GeomError(format!("invalid coordinate `{c}` in `{s}`")))?;
+        }
+        if coords.next().is_some() {
+            return Err(GeomError(format!("expected {N} coordinates in `{s}`")));
+        }
+
+        Ok(point)
+    }
+}
+
+/// One of the four orthogonal directions, where north points
+/// towards negative `y`, i.e. upwards in a grid of rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+    Around,
+}
+
+impl Direction {
+    /// Clockwise starting north, in the same order as [`crate::grid::DELTAS4`].
+    pub const ALL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    pub fn right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.left(),
+            Turn::Right => self.right(),
+            Turn::Around => self.reverse(),
+        }
+    }
+
+    /// The unit step in this direction.
+    pub fn delta<T: Coord>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Self::North => (T::ZERO, -T::ONE),
+            Self::East => (T::ONE, T::ZERO),
+            Self::South => (T::ZERO, T::ONE),
+            Self::West => (-T::ONE, T::ZERO),
+        };
+        Point([x, y])
+    }
+}
+
+/// The `(row, col)` offset for moving on a [`crate::grid::Grid`].
+impl From<Direction> for (isize, isize) {
+    fn from(dir: Direction) -> Self {
+        let delta = dir.delta::<isize>();
+        (delta.y(), delta.x())
+    }
+}
+
+/// Accepts `NESW`, `URDL` and `^>v<`.
+impl TryFrom<u8> for Direction {
+    type Error = GeomError;
+
+    fn try_from(b: u8) -> Result<Self, Self::Error> {
+        match b {
+            b'N' | b'U' | b'^' => Ok(Self::North),
+            b'E' | b'R' | b'>' => Ok(Self::East),
+            b'S' | b'D' | b'v' => Ok(Self::South),
+            b'W' | b'L' | b'<' => Ok(Self::West),
+            _ => Err(GeomError(format!("invalid direction `{}`", b as char))),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = GeomError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.as_bytes() {
+            [b] => Self::try_from(*b),
+            _ => Err(GeomError(format!("invalid direction `{s}`"))),
+        }
+    }
+}
+
+/// One of the eight compass headings, turning in steps of 45 degrees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Heading {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Heading {
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    pub fn right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Turns by 90 degrees, like a [`Direction`] would.
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.left().left(),
+            Turn::Right => self.right().right(),
+            Turn::Around => self.reverse(),
+        }
+    }
+
+    pub fn delta<T: Coord>(self) -> Point2<T> {
+        let straight = |dir: Direction| dir.delta();
+        match self {
+            Self::North => straight(Direction::North),
+            Self::NorthEast => straight(Direction::North) + straight(Direction::East),
+            Self::East => straight(Direction::East),
+            Self::SouthEast => straight(Direction::South) + straight(Direction::East),
+            Self::South => straight(Direction::South),
+            Self::SouthWest => straight(Direction::South) + straight(Direction::West),
+            Self::West => straight(Direction::West),
+            Self::NorthWest => straight(Direction::North) + straight(Direction::West),
+        }
+    }
+}
+
+impl From<Direction> for Heading {
+    fn from(dir: Direction) -> Self {
+        Self::ALL[dir as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::DELTAS4;
+
+    #[test]
+    fn test_point() {
+        let p = "3,-4".parse::<Point2>().unwrap();
+        assert_eq!(p, Point([3, -4]));
+        assert_eq!((p.x(), p.y()), (3, -4));
+        assert_eq!(p + Point([1, 1]), Point([4, -3]));
+        assert_eq!(p - Point([1, 1]), Point([2, -5]));
+        assert_eq!(-p * 2, Point([-6, 8]));
+        assert_eq!(p.signum(), Point([1, -1]));
+        assert_eq!(p.manhattan(Point::ORIGIN), 7);
+        assert_eq!(p.chebyshev(Point::ORIGIN), 4);
+        assert_eq!(p.to_string(), "3,-4");
+
+        let p = "1, 2, 3".parse::<Point3<i32>>().unwrap();
+        assert_eq!(p.z(), 3);
+        assert!("1,2".parse::<Point3>().is_err());
+        assert!("1,2,3,4".parse::<Point3>().is_err());
+        assert_eq!(
+            "1,x".parse::<Point2>().unwrap_err().to_string(),
+            "invalid coordinate `x` in `1,x`"
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let p = Point([0, 0, 0]);
+        assert_eq!(p.neighbors().count(), 6);
+        assert!(p.neighbors().all(|n| n.manhattan(p) == 1));
+        assert_eq!(p.adjacent().count(), 26);
+        assert!(p.adjacent().all(|n| n.chebyshev(p) == 1));
+
+        let points = [Point([1, 5]), Point([-2, 3]), Point([0, 7])];
+        let (lo, hi) = Point::bounds(points).unwrap();
+        assert_eq!((lo, hi), (Point([-2, 3]), Point([1, 7])));
+        assert!(Point([0, 4]).within(lo, hi));
+        assert!(!Point([0, 8]).within(lo, hi));
+    }
+
+    #[test]
+    fn test_direction() {
+        use Direction::*;
+
+        assert_eq!(North.right(), East);
+        assert_eq!(North.left(), West);
+        assert_eq!(East.turn(Turn::Around), West);
+        assert_eq!(b'v'.try_into().ok(), Some(South));
+        assert_eq!("L".parse::<Direction>().unwrap(), West);
+        assert!("X".parse::<Direction>().is_err());
+        assert_eq!(West.delta::<i32>(), Point([-1, 0]));
+        assert_eq!(Direction::ALL.map(<(isize, isize)>::from), DELTAS4);
+
+        assert_eq!(Heading::from(East).right(), Heading::SouthEast);
+        assert_eq!(Heading::NorthWest.turn(Turn::Right), Heading::NorthEast);
+        assert_eq!(Heading::SouthWest.delta::<i64>(), Point([-1, 1]));
+        assert_eq!(
+            Heading::ALL
+                .map(|h| h.delta::<i64>())
+                .into_iter()
+                .sum::<Point2>(),
+            Point::ORIGIN
+        );
+    }
+}
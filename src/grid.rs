@@ -0,0 +1,442 @@
+use std::{
+    convert::Infallible,
+    fmt::{Debug, Display, Write},
+    iter,
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+
+use crate::{numbered_lines, InputError, Position, PuzzleInput};
+
+/// A `(row, col)` position in a [`Grid`].
+pub type Pos = (usize, usize);
+
+/// Offsets of the 4 orthogonal neighbors, clockwise starting north.
+pub const DELTAS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets of all 8 neighbors, in row-major order.
+pub const DELTAS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A dense, row-major 2D grid with dimensions known only at runtime.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "grid is not {width}x{height}");
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses one row per non-empty line, converting every byte with `cell`.
+    pub fn parse_with<F, E>(input: &str, mut cell: F) -> Result<Self, InputError>
+    where
+        F: FnMut(u8) -> Result<T, E>,
+        E: Debug + 'static,
+    {
+        Self::parse_rows(input, |line| line.bytes().map(&mut cell).collect())
+    }
+
+    /// Parses one row per non-empty line, converting every char with `cell`.
+    pub fn parse_chars_with<F, E>(input: &str, mut cell: F) -> Result<Self, InputError>
+    where
+        F: FnMut(char) -> Result<T, E>,
+        E: Debug + 'static,
+    {
+        Self::parse_rows(input, |line| line.chars().map(&mut cell).collect())
+    }
+
+    fn parse_rows<F, E>(input: &str, mut row: F) -> Result<Self, InputError>
+    where
+        F: FnMut(&str) -> Result<Vec<T>, E>,
+        E: Debug + 'static,
+    {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+
+        for (n, line) in numbered_lines(input) {
+            let row = row(line).map_err(|e| InputError::line(n, line, e))?;
+            if *width.get_or_insert(row.len()) != row.len() {
+                return Err(InputError::message(
+                    line,
+                    format!("expected {} cells", width.unwrap_or_default()),
+                )
+                .at(Position::Line(n)));
+            }
+            cells.extend(row);
+        }
+
+        let width = width.unwrap_or_default();
+        let height = cells.len().checked_div(width).unwrap_or_default();
+
+        Ok(Self::new(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self[pos])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos).then(|| &mut self[pos])
+    }
+
+    /// Treats the grid as an infinite, repeating tiling of itself,
+    /// which only has no cells if the grid is empty.
+    pub fn get_wrapping(&self, row: isize, col: isize) -> Option<&T> {
+        if self.cells.is_empty() {
+            return None;
+        }
+        let row = row.rem_euclid(self.height as isize) as usize;
+        let col = col.rem_euclid(self.width as isize) as usize;
+        Some(&self[(row, col)])
+    }
+
+    /// Moves `pos` by `delta`, unless that would leave the grid.
+    pub fn offset(&self, (row, col): Pos, (dr, dc): (isize, isize)) -> Option<Pos> {
+        let pos = (row.checked_add_signed(dr)?, col.checked_add_signed(dc)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DELTAS4
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DELTAS8
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// All positions reached by repeatedly stepping `delta` away
+    /// from `pos`, excluding `pos` itself, until the edge of the grid.
+    pub fn ray(&self, pos: Pos, delta: (isize, isize)) -> impl Iterator<Item = Pos> + '_ {
+        assert_ne!(delta, (0, 0), "ray would never leave the grid");
+        iter::successors(self.offset(pos, delta), move |&pos| self.offset(pos, delta))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let (width, height) = (self.width, self.height);
+        (0..height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell in row-major order matching `pred`.
+    pub fn position<P>(&self, pred: P) -> Option<Pos>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let idx = self.cells.iter().position(pred)?;
+        Some((idx / self.width, idx % self.width))
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..][..self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn col(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(col < self.width, "column {col} is out of bounds");
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    /// The `\` diagonal through `pos`, from top-left to bottom-right,
+    /// or nothing if `pos` is outside of the grid.
+    pub fn diagonal(&self, pos @ (row, col): Pos) -> impl Iterator<Item = &T> {
+        let start = self.contains(pos).then(|| {
+            let k = row.min(col);
+            (row - k, col - k)
+        });
+        iter::successors(start, move |&pos| self.offset(pos, (1, 1))).map(|pos| &self[pos])
+    }
+
+    /// The `/` diagonal through `pos`, from top-right to bottom-left,
+    /// or nothing if `pos` is outside of the grid.
+    pub fn anti_diagonal(&self, pos @ (row, col): Pos) -> impl Iterator<Item = &T> {
+        let start = self.contains(pos).then(|| {
+            let k = row.min(self.width - 1 - col);
+            (row - k, col + k)
+        });
+        iter::successors(start, move |&pos| self.offset(pos, (1, -1))).map(|pos| &self[pos])
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|col| self.col(col).cloned())
+            .collect();
+        Self::new(self.height, self.width, cells)
+    }
+
+    /// Rotates the grid by 90 degrees clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|col| (0..self.height).rev().map(move |row| (row, col)))
+            .map(|pos| self[pos].clone())
+            .collect();
+        Self::new(self.height, self.width, cells)
+    }
+
+    /// Rotates the grid by 90 degrees counterclockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|col| (0..self.height).map(move |row| (row, col)))
+            .map(|pos| self[pos].clone())
+            .collect();
+        Self::new(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (row, col): Pos) -> &Self::Output {
+        assert!(col < self.width, "column {col} is out of bounds");
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, (row, col): Pos) -> &mut Self::Output {
+        assert!(col < self.width, "column {col} is out of bounds");
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T> PuzzleInput for Grid<T>
+where
+    T: TryFrom<u8>,
+    <T as TryFrom<u8>>::Error: Debug + 'static,
+{
+    type Out = Self;
+
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
+        Self::parse_with(input, T::try_from)
+    }
+}
+
+/// Parses a [`Grid`] from chars instead of bytes, for cell types
+/// that convert infallibly via `From<char>`.
+pub struct Chars<T>(PhantomData<T>);
+
+impl<T> PuzzleInput for Chars<T>
+where
+    T: From<char>,
+{
+    type Out = Grid<T>;
+
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
+        Grid::parse_chars_with(input, |c| Ok::<_, Infallible>(T::from(c)))
+    }
+}
+
+impl<T> Display for Grid<T>
+where
+    T: Copy + Into<char>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                f.write_char('\n')?;
+            }
+            for cell in row {
+                f.write_char((*cell).into())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        123
+        456
+    ";
+
+    fn grid() -> Grid<u8> {
+        Grid::from_input(INPUT).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], b'4');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.position(|&c| c == b'6'), Some((1, 2)));
+        assert_eq!(grid.to_string(), "123\n456");
+
+        let err = Grid::<u8>::from_input("12\n345").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"line 2: cannot parse "345": expected 2 cells"#
+        );
+
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        enum Cell {
+            Open,
+            Wall,
+        }
+
+        impl TryFrom<u8> for Cell {
+            type Error = char;
+
+            fn try_from(b: u8) -> Result<Self, Self::Error> {
+                match b {
+                    b'.' => Ok(Cell::Open),
+                    b'#' => Ok(Cell::Wall),
+                    _ => Err(b as char),
+                }
+            }
+        }
+
+        let cells = Grid::<Cell>::from_input(".#\n#.").unwrap();
+        assert_eq!(cells[(0, 1)], Cell::Wall);
+        assert_eq!(cells[(1, 1)], Cell::Open);
+        let err = Grid::<Cell>::from_input(".#\n#x").unwrap_err();
+        assert_eq!(err.to_string(), r##"line 2: cannot parse "#x": 'x'"##);
+    }
+
+    #[test]
+    fn test_parse_chars() {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        enum Cell {
+            Digit(u32),
+            Other(char),
+        }
+
+        impl From<char> for Cell {
+            fn from(c: char) -> Self {
+                c.to_digit(10).map_or(Cell::Other(c), Cell::Digit)
+            }
+        }
+
+        let cells = Chars::<Cell>::from_input("1é\n#2").unwrap();
+        assert_eq!((cells.width(), cells.height()), (2, 2));
+        assert_eq!(cells[(0, 1)], Cell::Other('é'));
+        assert_eq!(cells[(1, 1)], Cell::Digit(2));
+
+        let err = Chars::<char>::from_input("ab\nc").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"line 2: cannot parse "c": expected 2 cells"#
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbors8((1, 1)).collect::<Vec<_>>(),
+            [(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)]
+        );
+        assert_eq!(
+            grid.ray((0, 0), (0, 1)).collect::<Vec<_>>(),
+            [(0, 1), (0, 2)]
+        );
+        assert_eq!(grid.get_wrapping(-1, 4), Some(&b'5'));
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = grid();
+        assert_eq!(grid.row(1), b"456");
+        assert_eq!(grid.col(2).copied().collect::<Vec<_>>(), b"36");
+        assert_eq!(grid.diagonal((1, 2)).copied().collect::<Vec<_>>(), b"26");
+        assert_eq!(
+            grid.anti_diagonal((1, 0)).copied().collect::<Vec<_>>(),
+            b"24"
+        );
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.rotate_cw().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_ccw().to_string(), "36\n25\n14");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+
+    #[test]
+    fn test_out_of_bounds() {
+        let grid = grid();
+        assert_eq!(grid.diagonal((2, 0)).count(), 0);
+        assert_eq!(grid.anti_diagonal((0, 3)).count(), 0);
+
+        let empty = Grid::<u8>::from_input("").unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.get_wrapping(0, 0), None);
+        assert_eq!(empty.get((0, 0)), None);
+        assert_eq!(empty.neighbors8((0, 0)).count(), 0);
+        assert_eq!(empty.diagonal((0, 0)).count(), 0);
+        assert_eq!(empty.anti_diagonal((0, 0)).count(), 0);
+    }
+}
@@ -1,3 +1,23 @@
+pub mod animate;
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod boxes;
+pub mod cli;
+pub mod cycle;
+pub mod geom;
+pub mod grid;
+pub mod log;
+pub mod memo;
+pub mod ocr;
+pub mod parse;
+pub mod ranges;
+pub mod report;
+pub mod runner;
+pub mod search;
+pub mod span;
+pub mod viz;
+
 use std::{
     fmt::Debug,
     fmt::Display,
@@ -6,18 +26,43 @@ use std::{
     time::{Duration, Instant},
 };
 
+use answers::Verdict;
+use bench::Stats;
+
+/// Logs a message at the given [`log::Level`], see the [`log`](mod@log) module
+/// for filtering.
 #[macro_export]
-macro_rules! poop {
-    ($($arg:tt)*) => {
-        {
-            #[cfg(debug_assertions)]
-            {
-                println!($($arg)*);
-            }
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::ENABLED && $crate::log::enabled($level, ::std::module_path!()) {
+            $crate::log::write($level, ::std::module_path!(), ::std::format_args!($($arg)+));
         }
     };
 }
 
+/// Times the rest of the enclosing block, or only `$expr`, as a step
+/// of the running part, see the [`span`](mod@span) module.
+#[macro_export]
+macro_rules! span {
+    ($name:expr) => {
+        let _span = $crate::span::enter($name);
+    };
+    ($name:expr, $expr:expr) => {{
+        let _span = $crate::span::enter($name);
+        $expr
+    }};
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
 pub trait MedianExt<T> {
     fn median(self) -> T;
 }
@@ -37,26 +82,131 @@ impl<T: Ord + Copy> MedianExt<T> for Vec<T> {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    Line(usize),
+    Block(usize),
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Line(n) => write!(f, "line {n}"),
+            Self::Block(n) => write!(f, "block {n}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Cause {
+    Parse(Box<dyn Debug>),
+    Message(String),
+    Nested(Box<InputError>),
+}
+
+/// An error that occurred while parsing the puzzle input.
+///
+/// Line and block numbers are 1-based and relative to the
+/// enclosing input, i.e. a line number inside a block error
+/// refers to the line within that block.
+#[derive(Debug)]
+pub struct InputError {
+    position: Option<Position>,
+    text: String,
+    cause: Cause,
+}
+
+impl InputError {
+    pub fn new<E>(text: &str, err: E) -> Self
+    where
+        E: Debug + 'static,
+    {
+        Self {
+            position: None,
+            text: text.to_string(),
+            cause: Cause::Parse(Box::new(err)),
+        }
+    }
+
+    pub fn line<E>(line: usize, text: &str, err: E) -> Self
+    where
+        E: Debug + 'static,
+    {
+        Self::new(text, err).at(Position::Line(line))
+    }
+
+    pub fn block(block: usize, text: &str, err: Self) -> Self {
+        Self {
+            position: Some(Position::Block(block)),
+            text: text.to_string(),
+            cause: Cause::Nested(Box::new(err)),
+        }
+    }
+
+    pub fn message<T>(text: &str, message: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            position: None,
+            text: text.to_string(),
+            cause: Cause::Message(message.into()),
+        }
+    }
+
+    #[must_use]
+    pub fn at(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn position(&self) -> Option<Position> {
+        self.position
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(position) = self.position {
+            write!(f, "{position}: ")?;
+        }
+        match &self.cause {
+            Cause::Nested(inner) => write!(f, "{inner}"),
+            Cause::Parse(err) => write!(f, "cannot parse {:?}: {err:?}", self.text),
+            Cause::Message(msg) if self.text.is_empty() => write!(f, "{msg}"),
+            Cause::Message(msg) => write!(f, "cannot parse {:?}: {msg}", self.text),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
 pub trait PuzzleInput
 where
     Self: Sized,
 {
     type Out;
 
-    fn from_input(input: &str) -> Self::Out;
+    fn from_input(input: &str) -> Result<Self::Out, InputError>;
 }
 
 pub trait ProcessInput {
     type In: PuzzleInput;
     type Out;
 
-    fn process(input: <Self::In as PuzzleInput>::Out) -> Self::Out;
+    fn process(input: <Self::In as PuzzleInput>::Out) -> Result<Self::Out, InputError>;
 }
 
 impl PuzzleInput for () {
     type Out = Self;
 
-    fn from_input(_input: &str) -> Self::Out {}
+    fn from_input(_input: &str) -> Result<Self::Out, InputError> {
+        Ok(())
+    }
 }
 
 pub struct Blocks<T>(PhantomData<T>);
@@ -67,22 +217,60 @@ where
 {
     type Out = Vec<T::Out>;
 
-    fn from_input(input: &str) -> Self::Out {
-        input.split("\n\n").map(|l| T::from_input(l)).collect()
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
+        input
+            .split("\n\n")
+            .enumerate()
+            .map(|(i, block)| T::from_input(block).map_err(|e| InputError::block(i + 1, block, e)))
+            .collect()
     }
 }
 
+/// Splits the input at blank lines and parses each section with the
+/// matching element of the tuple `T`. The last element receives all
+/// remaining sections, so it can be e.g. [`Blocks`] itself.
+pub struct Sections<T>(PhantomData<T>);
+
+macro_rules! sections {
+    ($n:literal; $($idx:tt $t:ident),+) => {
+        impl<$($t),+> PuzzleInput for Sections<($($t,)+)>
+        where
+            $($t: PuzzleInput,)+
+        {
+            type Out = ($($t::Out,)+);
+
+            fn from_input(input: &str) -> Result<Self::Out, InputError> {
+                let sections = input.splitn($n, "\n\n").collect::<Vec<_>>();
+                if sections.len() < $n {
+                    let message = format!("expected {} sections, found {}", $n, sections.len());
+                    return Err(InputError::message("", message));
+                }
+                Ok(($(
+                    $t::from_input(sections[$idx])
+                        .map_err(|e| InputError::block($idx + 1, sections[$idx], e))?,
+                )+))
+            }
+        }
+    };
+}
+
+sections!(2; 0 A, 1 B);
+sections!(3; 0 A, 1 B, 2 C);
+sections!(4; 0 A, 1 B, 2 C, 3 D);
+
 pub struct Parsing<T>(PhantomData<T>);
 
 impl<T> PuzzleInput for Parsing<T>
 where
     T: FromStr,
-    <T as FromStr>::Err: Debug,
+    <T as FromStr>::Err: Debug + 'static,
 {
     type Out = Vec<T>;
 
-    fn from_input(input: &str) -> Self::Out {
-        lines(input).map(|l| T::from_str(l).unwrap()).collect()
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
+        numbered_lines(input)
+            .map(|(n, l)| T::from_str(l).map_err(|e| InputError::line(n, l, e)))
+            .collect()
     }
 }
 
@@ -94,8 +282,8 @@ where
 {
     type Out = Vec<T>;
 
-    fn from_input(input: &str) -> Self::Out {
-        lines(input).map(|l| T::from(l)).collect()
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
+        Ok(lines(input).map(|l| T::from(l)).collect())
     }
 }
 
@@ -107,8 +295,8 @@ where
 {
     type Out = T::Out;
 
-    fn from_input(input: &str) -> Self::Out {
-        let input = T::In::from_input(input);
+    fn from_input(input: &str) -> Result<Self::Out, InputError> {
+        let input = T::In::from_input(input)?;
         T::process(input)
     }
 }
@@ -124,32 +312,50 @@ where
 
     type Out = <T::Out as PopFirst>::Out;
 
-    fn process(input: <T as PuzzleInput>::Out) -> Self::Out {
+    fn process(input: <T as PuzzleInput>::Out) -> Result<Self::Out, InputError> {
         <T::Out as PopFirst>::pop_first(input)
+            .ok_or_else(|| InputError::message("", "expected at least one item"))
     }
 }
 
 pub trait PopFirst {
     type Out;
 
-    fn pop_first(self) -> Self::Out;
+    fn pop_first(self) -> Option<Self::Out>;
 }
 
 impl<T> PopFirst for Vec<T> {
     type Out = T;
 
-    fn pop_first(self) -> Self::Out {
-        self.into_iter().next().unwrap()
+    fn pop_first(self) -> Option<Self::Out> {
+        self.into_iter().next()
     }
 }
 
 pub fn lines(s: &str) -> impl Iterator<Item = &str> + '_ {
-    s.lines().map(str::trim).filter(|line| !line.is_empty())
+    numbered_lines(s).map(|(_, line)| line)
+}
+
+/// Like [`lines`], but also yields the 1-based line number of each line.
+pub fn numbered_lines(s: &str) -> impl Iterator<Item = (usize, &str)> + '_ {
+    s.lines()
+        .map(str::trim)
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| (i + 1, line))
+}
+
+/// Runs `f` and measures how long it took.
+#[inline]
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let res = f();
+    (res, start.elapsed())
 }
 
-pub struct PuzzleSolution<T> {
+pub struct PuzzleSolution<T, U = T> {
     pub part1: T,
-    pub part2: T,
+    pub part2: U,
     pub parse_time: Duration,
     pub part1_time: Duration,
     pub part2_time: Duration,
@@ -157,48 +363,81 @@ pub struct PuzzleSolution<T> {
 
 pub trait Solution {
     type Input: PuzzleInput;
-    type Output;
+    type Output1;
+    type Output2;
 
     fn puzzle_input() -> &'static str;
 
+    fn puzzle_file() -> &'static str;
+
     #[inline]
-    fn parse_input(input: &str) -> <Self::Input as PuzzleInput>::Out {
+    fn parse_input(input: &str) -> Result<<Self::Input as PuzzleInput>::Out, InputError> {
         <Self::Input as PuzzleInput>::from_input(input)
     }
 
+    fn part1(input: &<Self::Input as PuzzleInput>::Out) -> Self::Output1;
+
+    fn part2(input: &<Self::Input as PuzzleInput>::Out) -> Self::Output2;
+
     fn run(
-        input: <Self::Input as PuzzleInput>::Out,
+        input: &<Self::Input as PuzzleInput>::Out,
         parse_time: Duration,
-    ) -> PuzzleSolution<Self::Output>;
+    ) -> PuzzleSolution<Self::Output1, Self::Output2> {
+        let (part1, part1_time) = timed(|| Self::part1(input));
+        let (part2, part2_time) = timed(|| Self::part2(input));
+
+        PuzzleSolution {
+            part1,
+            part2,
+            part1_time,
+            part2_time,
+            parse_time,
+        }
+    }
 
-    fn solve() -> PuzzleSolution<Self::Output> {
-        let input = Self::puzzle_input();
-        let start = Instant::now();
-        let input = Self::parse_input(input);
-        let parse_time = start.elapsed();
-        Self::run(input, parse_time)
+    fn solve() -> Result<PuzzleSolution<Self::Output1, Self::Output2>, InputError> {
+        Self::solve_on(Self::puzzle_input())
+    }
+
+    fn solve_on(input: &str) -> Result<PuzzleSolution<Self::Output1, Self::Output2>, InputError> {
+        let (input, parse_time) = timed(|| Self::parse_input(input));
+        Ok(Self::run(&input?, parse_time))
     }
 }
 
 pub trait SolutionExt: Solution {
-    fn run_on(input: &str) -> (Self::Output, Self::Output) {
-        let input = Self::parse_input(input);
-        let PuzzleSolution { part1, part2, .. } = Self::run(input, Duration::ZERO);
+    fn run_on(input: &str) -> (Self::Output1, Self::Output2) {
+        let input = Self::parse_input(input).unwrap_or_else(|e| panic!("{e}"));
+        let PuzzleSolution { part1, part2, .. } = Self::run(&input, Duration::ZERO);
         (part1, part2)
     }
 
-    fn run_on_input() -> (Self::Output, Self::Output) {
+    fn run_on_input() -> (Self::Output1, Self::Output2) {
         let input = Self::puzzle_input();
         Self::run_on(input)
     }
+
+    fn part1_on(input: &str) -> Self::Output1 {
+        let input = Self::parse_input(input).unwrap_or_else(|e| panic!("{e}"));
+        Self::part1(&input)
+    }
+
+    fn part2_on(input: &str) -> Self::Output2 {
+        let input = Self::parse_input(input).unwrap_or_else(|e| panic!("{e}"));
+        Self::part2(&input)
+    }
 }
 
 impl<T: Solution> SolutionExt for T {}
 
 pub struct ResultLine {
     prefix: String,
-    duration: Duration,
+    duration: Option<Duration>,
+    stats: Option<Stats>,
     solution: Option<Box<dyn Display>>,
+    verdict: Option<Verdict>,
+    spans: Vec<span::Span>,
+    failed: bool,
 }
 
 impl ResultLine {
@@ -206,21 +445,71 @@ impl ResultLine {
     where
         T: Display + 'static,
     {
-        Self::new(format!("Part {part}"), duration, Some(Box::new(solution)))
+        Self::new(
+            format!("Part {part}"),
+            Some(duration),
+            Some(Box::new(solution)),
+        )
     }
 
     pub fn note<T>(note: &T, duration: Duration) -> Self
     where
         T: Display + ?Sized,
     {
-        Self::new(note.to_string(), duration, None)
+        Self::new(note.to_string(), Some(duration), None)
+    }
+
+    pub fn value<T, V>(note: &T, duration: Duration, value: V) -> Self
+    where
+        T: Display + ?Sized,
+        V: Display + 'static,
+    {
+        Self::new(note.to_string(), Some(duration), Some(Box::new(value)))
+    }
+
+    pub fn failure<T, E>(note: &T, error: E) -> Self
+    where
+        T: Display + ?Sized,
+        E: Display + 'static,
+    {
+        Self {
+            failed: true,
+            ..Self::new(note.to_string(), None, Some(Box::new(error)))
+        }
+    }
+
+    pub fn failed(self, failed: bool) -> Self {
+        Self { failed, ..self }
+    }
+
+    pub fn stats(self, stats: Stats) -> Self {
+        Self {
+            stats: Some(stats),
+            ..self
+        }
+    }
+
+    pub fn verdict(self, verdict: Verdict) -> Self {
+        Self {
+            verdict: Some(verdict),
+            ..self
+        }
+    }
+
+    /// Spans to print as a tree below the line.
+    pub fn spans(self, spans: Vec<span::Span>) -> Self {
+        Self { spans, ..self }
     }
 
-    fn new(prefix: String, duration: Duration, solution: Option<Box<dyn Display>>) -> Self {
+    fn new(prefix: String, duration: Option<Duration>, solution: Option<Box<dyn Display>>) -> Self {
         Self {
             prefix,
             duration,
+            stats: None,
             solution,
+            verdict: None,
+            spans: Vec::new(),
+            failed: false,
         }
     }
 }
@@ -230,7 +519,11 @@ impl Display for ResultLine {
         use owo_colors::{OwoColorize, Stream::Stdout};
         const DEFAULT_WIDTH: usize = 42;
 
-        let duration = format!(" ({})", humantime::format_duration(self.duration));
+        let duration = match (self.stats, self.duration) {
+            (Some(stats), _) => format!(" ({stats})"),
+            (None, Some(d)) => format!(" ({})", humantime::format_duration(d)),
+            (None, None) => String::new(),
+        };
 
         write!(
             f,
@@ -253,28 +546,61 @@ impl Display for ResultLine {
 
             let solution = solution.to_string();
             let mut solution = solution.lines().filter(|l| !l.is_empty());
+            let failed = self.failed || matches!(self.verdict, Some(Verdict::Incorrect { .. }));
+            let style = |line: &str| {
+                if failed {
+                    line.if_supports_color(Stdout, |t| t.red()).to_string()
+                } else {
+                    line.if_supports_color(Stdout, |t| t.bold()).to_string()
+                }
+            };
 
-            write!(
-                f,
-                "{}",
-                solution
-                    .next()
-                    .unwrap()
-                    .if_supports_color(Stdout, |t| t.bold())
-            )?;
+            write!(f, "{}", style(solution.next().unwrap_or_default()))?;
+
+            match &self.verdict {
+                Some(Verdict::Correct) => {
+                    write!(f, " {}", "✓".if_supports_color(Stdout, |t| t.green()))?;
+                }
+                Some(Verdict::Incorrect { expected }) => {
+                    write!(f, " {}", "✗".if_supports_color(Stdout, |t| t.red()))?;
+                    if !expected.contains('\n') {
+                        let expected = format!("(expected {expected})");
+                        write!(f, " {}", expected.if_supports_color(Stdout, |t| t.dimmed()))?;
+                    }
+                }
+                Some(Verdict::Unknown) => {
+                    write!(f, " {}", "?".if_supports_color(Stdout, |t| t.dimmed()))?;
+                }
+                None => {}
+            }
 
             for line in solution {
                 writeln!(f)?;
-                write!(
-                    f,
-                    "{:>w$}    {}",
-                    "",
-                    line.if_supports_color(Stdout, |t| t.bold()),
-                    w = max_width
-                )?;
+                write!(f, "{:>w$}    {}", "", style(line), w = max_width)?;
             }
         }
 
+        let mut spans = self.spans.iter().map(|s| (1, s)).rev().collect::<Vec<_>>();
+        while let Some((depth, span)) = spans.pop() {
+            let time = humantime::format_duration(span.time).to_string();
+            let calls = if span.calls > 1 {
+                format!(", {} calls", span.calls)
+            } else {
+                String::new()
+            };
+            let time = format!("({time}{calls})");
+            writeln!(f)?;
+            write!(
+                f,
+                "{:w$}- {} {}",
+                "",
+                span.name,
+                time.if_supports_color(Stdout, |t| t.dimmed()),
+                w = depth * 4
+            )?;
+            spans.extend(span.children.iter().map(|s| (depth + 1, s)).rev());
+        }
+
         Ok(())
     }
 }
@@ -285,6 +611,10 @@ macro_rules! input {
         $input_ty
     };
 
+    (sections ($($input_ty:ty),+ $(,)?)) => {
+        $crate::Sections<($($input_ty,)+)>
+    };
+
     ($input_ty:ty) => {
         $crate::As<$input_ty>
     };
@@ -308,16 +638,49 @@ macro_rules! input {
     (first $input_ty:ty) => {
         input!(process $crate::First<$input_ty>)
     };
+
+    (grid chars $cell_ty:ty) => {
+        $crate::grid::Chars<$cell_ty>
+    };
+
+    (grid $cell_ty:ty) => {
+        $crate::grid::Grid<$cell_ty>
+    };
 }
 
 #[macro_export]
 macro_rules! register {
-    ($file:literal; ($input:ident: $input_ty:ty) -> $output_ty:ty { $part1:expr; $part2:expr $(;)? }) => {
+    (@examples) => {};
+    (@examples $($example:expr => $($part:ident $expected:expr),+;)+) => {
+        #[test]
+        fn test_ex() {
+            let mut example = 0;
+            $(
+                example += 1;
+                let input = <Solver as $crate::Solution>::parse_input($example)
+                    .unwrap_or_else(|e| panic!("example {example}: {e}"));
+                $(
+                    assert_eq!(
+                        <Solver as $crate::Solution>::$part(&input),
+                        $expected,
+                        "{} of example {example}",
+                        stringify!($part)
+                    );
+                )+
+            )+
+        }
+    };
+    (
+        $file:literal;
+        ($input:ident: $input_ty:ty) -> ($output1_ty:ty, $output2_ty:ty) { $part1:expr; $part2:expr $(;)? }
+        $(example $example:expr => $($part:ident $expected:expr),+;)*
+    ) => {
         pub(crate) struct Solver;
 
         impl $crate::Solution for Solver {
             type Input = $input_ty;
-            type Output = $output_ty;
+            type Output1 = $output1_ty;
+            type Output2 = $output2_ty;
 
             #[inline]
             fn puzzle_input() -> &'static str {
@@ -325,65 +688,100 @@ macro_rules! register {
             }
 
             #[inline]
-            fn run(
-                mut $input: <$input_ty as $crate::PuzzleInput>::Out,
-                parse_time: ::std::time::Duration,
-            ) -> $crate::PuzzleSolution<Self::Output> {
-                let start = ::std::time::Instant::now();
-                let part1 = $part1;
-                let part1_time = start.elapsed();
-                let start = ::std::time::Instant::now();
-                let part2 = $part2;
-                let part2_time = start.elapsed();
-
-                $crate::PuzzleSolution {
-                    part1,
-                    part2,
-                    part1_time,
-                    part2_time,
-                    parse_time,
-                }
+            fn puzzle_file() -> &'static str {
+                $file
+            }
+
+            #[inline]
+            #[allow(unused_variables)]
+            fn part1($input: &<$input_ty as $crate::PuzzleInput>::Out) -> Self::Output1 {
+                $part1
+            }
+
+            #[inline]
+            #[allow(unused_variables)]
+            fn part2($input: &<$input_ty as $crate::PuzzleInput>::Out) -> Self::Output2 {
+                $part2
             }
         }
+
+        #[cfg(test)]
+        mod tests {
+            #[allow(unused_imports)]
+            use super::*;
+            use $crate::{Solution, SolutionExt};
+
+            $crate::register!(@examples $($example => $($part $expected),+;)*);
+
+            #[test]
+            fn test() {
+                let (part1, part2) = Solver::run_on_input();
+                $crate::answers::assert_answers(
+                    ::std::include_str!("input/answers.toml"),
+                    $file,
+                    &part1,
+                    &part2,
+                );
+            }
+
+            #[bench]
+            fn bench_parsing(b: &mut ::test::Bencher) {
+                let input = Solver::puzzle_input();
+                b.bytes = input.len() as u64;
+                b.iter(|| Solver::parse_input(input));
+            }
+
+            #[bench]
+            fn bench_pt1(b: &mut ::test::Bencher) {
+                let input = Solver::parse_input(Solver::puzzle_input()).unwrap();
+                b.iter(|| Solver::part1(&input));
+            }
+
+            #[bench]
+            fn bench_pt2(b: &mut ::test::Bencher) {
+                let input = Solver::parse_input(Solver::puzzle_input()).unwrap();
+                b.iter(|| Solver::part2(&input));
+            }
+        }
+    };
+    (
+        $file:literal;
+        ($input:ident: $input_ty:ty) -> $output_ty:ty { $part1:expr; $part2:expr $(;)? }
+        $(example $example:expr => $($part:ident $expected:expr),+;)*
+    ) => {
+        $crate::register!(
+            $file;
+            ($input: $input_ty) -> ($output_ty, $output_ty) { $part1; $part2 }
+            $(example $example => $($part $expected),+;)*
+        );
     };
 }
 
 #[macro_export]
-macro_rules! aoc_main {
+macro_rules! aoc_year {
     ($($day:literal => $md:ident),+ $(,)?) => {
-        use ::aoc::{PuzzleSolution, Solution};
-        $(mod $md);+;
-
-        fn main() {
-            let suppress_output = ::std::env::var_os("AOC_NO_OUTPUT").is_some();
-            let mut total_time = ::std::time::Duration::ZERO;
-            ::std::env::args()
-                .skip(1)
-                .flat_map(|s| s.parse::<u8>())
-                .for_each(|day| match day {
-                    $(
-                        $day => {
-                            let solution = $md::Solver::solve();
-                            let day_time = solution.parse_time + solution.part1_time + solution.part2_time;
-                            total_time += day_time;
-
-                            if !suppress_output {
-                                println!("Day {:02}", day);
-                                println!("  - {}", $crate::ResultLine::note("Parsing", solution.parse_time));
-                                println!("  - {}", $crate::ResultLine::solution(1, solution.part1_time, solution.part1));
-                                println!("  - {}", $crate::ResultLine::solution(2, solution.part2_time, solution.part2));
-                                println!("  - {}", $crate::ResultLine::note("Total", day_time));
-                                println!();
-                            }
-                        }
-                    ),+,
-                    x => unimplemented!("Day {} is not yet implemented", x),
-                });
-
-                if !suppress_output {
-                    let total_time = ::humantime::format_duration(total_time);
-                    println!("Total time: {}", total_time);
-                }
+        $(mod $md;)+
+
+        pub(crate) fn register(registry: &mut $crate::runner::Registry, year: u16) {
+            registry.register_answers(year, ::std::include_str!("input/answers.toml"));
+            $(
+                registry.register::<$md::Solver>(year, $day);
+            )+
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! aoc_main {
+    ($($year:literal => $md:ident),+ $(,)?) => {
+        $(mod $md;)+
+
+        fn main() -> ::std::process::ExitCode {
+            let mut registry = $crate::runner::Registry::default();
+            $(
+                $md::register(&mut registry, $year);
+            )+
+            $crate::runner::main(env!("CARGO_BIN_NAME"), &registry)
         }
     };
 }
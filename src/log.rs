@@ -0,0 +1,169 @@
+//! Leveled logging for solutions, see [`debug!`](crate::debug) and [`trace!`](crate::trace).
+//!
+//! Messages go to stderr, prefixed with the year and day of the module
+//! they were logged from, e.g. `[2022/16 DEBUG]`. Release builds compile
+//! logging away unless the `log` feature is enabled.
+
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        OnceLock,
+    },
+};
+
+/// Whether log statements are compiled in at all.
+pub const ENABLED: bool = cfg!(any(debug_assertions, feature = "log"));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Debug = 1,
+    Trace,
+}
+
+impl Level {
+    /// The most detailed level shown for `-v` repeated `verbosity` times,
+    /// where `--log` without `-v` counts as `-v`.
+    pub fn from_verbosity(verbosity: u8) -> Self {
+        match verbosity {
+            0 | 1 => Self::Debug,
+            _ => Self::Trace,
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Debug => "DEBUG",
+            Self::Trace => "TRACE",
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct LogError(String);
+
+impl Display for LogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for LogError {}
+
+/// A year or a single day whose messages are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Target {
+    pub year: u16,
+    pub day: Option<u8>,
+}
+
+impl Target {
+    /// The year and day of a module like `aoc::aoc2022::day16`.
+    fn of_module(module: &str) -> Option<(u16, Option<u8>)> {
+        let mut year = None;
+        let mut day = None;
+        for segment in module.split("::") {
+            if let Some(y) = segment.strip_prefix("aoc").and_then(|y| y.parse().ok()) {
+                year = Some(y);
+            } else if let Some(d) = segment.strip_prefix("day").and_then(|d| d.parse().ok()) {
+                day = Some(d);
+            }
+        }
+        Some((year?, day))
+    }
+
+    fn matches(self, module: &str) -> bool {
+        Self::of_module(module)
+            .is_some_and(|(year, day)| year == self.year && self.day.is_none_or(|d| Some(d) == day))
+    }
+}
+
+/// Parses `<YEAR>` or `<YEAR>/<DAY>`.
+impl FromStr for Target {
+    type Err = LogError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || LogError(format!("invalid log target `{s}`, expected e.g. `2022/16`"));
+        let (year, day) = match s.split_once('/') {
+            Some((year, day)) => (year, Some(day.parse().map_err(|_| invalid())?)),
+            None => (s, None),
+        };
+        let year = year
+            .parse()
+            .ok()
+            .filter(|year| (2015..=2100).contains(year))
+            .ok_or_else(invalid)?;
+        Ok(Self { year, day })
+    }
+}
+
+/// Nothing is shown until [`init`] is called.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+
+static TARGETS: OnceLock<Vec<Target>> = OnceLock::new();
+
+/// Shows messages up to `level`, only from `targets` unless it is empty.
+pub fn init(level: Level, targets: Vec<Target>) -> Result<(), LogError> {
+    if !ENABLED {
+        return Err(LogError(
+            "logging requires a debug build or the `log` feature".to_string(),
+        ));
+    }
+    MAX_LEVEL.store(level as u8, Ordering::Relaxed);
+    TARGETS
+        .set(targets)
+        .map_err(|_| LogError("logging is already configured".to_string()))
+}
+
+/// Whether a message at `level` from `module` is shown.
+pub fn enabled(level: Level, module: &str) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+        && TARGETS
+            .get()
+            .is_none_or(|targets| targets.is_empty() || targets.iter().any(|t| t.matches(module)))
+}
+
+#[doc(hidden)]
+pub fn write(level: Level, module: &str, args: fmt::Arguments<'_>) {
+    match Target::of_module(module) {
+        Some((year, Some(day))) => eprintln!("[{year}/{day:02} {level}] {args}"),
+        Some((year, None)) => eprintln!("[{year} {level}] {args}"),
+        None => eprintln!("[{module} {level}] {args}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target() {
+        assert_eq!(
+            "2022/16".parse::<Target>().unwrap(),
+            Target {
+                year: 2022,
+                day: Some(16)
+            }
+        );
+        assert_eq!(
+            "2021".parse::<Target>().unwrap(),
+            Target {
+                year: 2021,
+                day: None
+            }
+        );
+        assert!("16".parse::<Target>().is_err());
+        assert!("2022/x".parse::<Target>().is_err());
+
+        let day = "2022/16".parse::<Target>().unwrap();
+        let year = "2022".parse::<Target>().unwrap();
+        assert!(day.matches("aoc::aoc2022::day16"));
+        assert!(!day.matches("aoc::aoc2022::day1"));
+        assert!(!day.matches("aoc::aoc2021::day16"));
+        assert!(year.matches("aoc::aoc2022::day1::tests"));
+        assert!(!year.matches("aoc::search"));
+    }
+}
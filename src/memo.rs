@@ -0,0 +1,141 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+    hash::{BuildHasher, BuildHasherDefault, Hash},
+};
+
+use fxhash::FxHasher;
+
+/// A cache for memoizing a (recursive) function during a single solve.
+///
+/// Create one per call to `part1`/`part2` and pass it down the recursion,
+/// so that neither repeated solves nor benchmark iterations share results.
+pub struct Memo<K, V, S = BuildHasherDefault<FxHasher>> {
+    cache: HashMap<K, V, S>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K, V> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::with_hasher(BuildHasherDefault::default())
+    }
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, S> Memo<K, V, S> {
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            cache: HashMap::with_hasher(hasher),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+}
+
+impl<K, V, S> Memo<K, V, S>
+where
+    K: Eq + Hash,
+    V: Clone,
+    S: BuildHasher,
+{
+    /// Returns the cached value for `key`, or computes and caches it.
+    /// `compute` receives the memo itself to be able to recurse.
+    pub fn get_or_insert_with<F>(&mut self, key: K, compute: F) -> V
+    where
+        F: FnOnce(&mut Self) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+}
+
+impl<K, V, S> Debug for Memo<K, V, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Memo")
+            .field("entries", &self.len())
+            .field("hits", &self.hits)
+            .field("misses", &self.misses)
+            .finish()
+    }
+}
+
+impl<K, V, S> Display for Memo<K, V, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lookups = self.hits + self.misses;
+        let rate = if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64 * 100.0
+        };
+        write!(
+            f,
+            "{} entries, {} hits, {} misses ({rate:.1}% hit rate)",
+            self.len(),
+            self.hits,
+            self.misses
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::RandomState;
+
+    use super::*;
+
+    fn fib<S: BuildHasher>(memo: &mut Memo<u64, u64, S>, n: u64) -> u64 {
+        memo.get_or_insert_with(n, |memo| match n {
+            0 | 1 => n,
+            _ => fib(memo, n - 1) + fib(memo, n - 2),
+        })
+    }
+
+    #[test]
+    fn test_memo() {
+        let mut memo = Memo::new();
+        assert_eq!(fib(&mut memo, 50), 12586269025);
+        assert_eq!(memo.len(), 51);
+        assert_eq!((memo.hits(), memo.misses()), (48, 51));
+        assert_eq!(
+            memo.to_string(),
+            "51 entries, 48 hits, 51 misses (48.5% hit rate)"
+        );
+        assert_eq!(
+            format!("{memo:?}"),
+            "Memo { entries: 51, hits: 48, misses: 51 }"
+        );
+
+        let mut memo = Memo::with_hasher(RandomState::new());
+        assert_eq!(fib(&mut memo, 10), 55);
+        assert_eq!(memo.misses(), 11);
+    }
+}
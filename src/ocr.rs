@@ -0,0 +1,184 @@
+use std::{convert::Infallible, fmt::Display, ops::RangeInclusive};
+
+use crate::{geom::Point2, grid::Grid};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OcrError(String);
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+/// Letters that are 6 pixels high and usually 4 wide, with empty
+/// columns on either side removed.
+const SMALL: [(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Letters that are 10 pixels high and 6 wide.
+#[rustfmt::skip]
+const LARGE: [(char, [&str; 10]); 15] = [
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+/// Reads the letters in a picture of `#` (lit) and `.` (dark) pixels.
+pub fn read_str(picture: &str) -> Result<String, OcrError> {
+    let image = Grid::parse_with(picture, |b| Ok::<_, Infallible>(b == b'#'))
+        .map_err(|e| OcrError(e.to_string()))?;
+    read(&image)
+}
+
+/// Reads the letters formed by a set of lit points, with `y` growing downwards.
+pub fn read_points<I>(points: I) -> Result<String, OcrError>
+where
+    I: IntoIterator<Item = Point2>,
+{
+    let points = points.into_iter().collect::<Vec<_>>();
+    let (min, max) = Point2::bounds(points.iter().copied())
+        .ok_or_else(|| OcrError("there are no letters".to_string()))?;
+
+    let size = max - min;
+    let mut image = Grid::filled(size.x() as usize + 1, size.y() as usize + 1, false);
+    for point in points {
+        let point = point - min;
+        image[(point.y() as usize, point.x() as usize)] = true;
+    }
+
+    read(&image)
+}
+
+/// Reads the letters in `image`, which must be separated by empty columns.
+/// The font is chosen by the height of the lit part of the image.
+pub fn read(image: &Grid<bool>) -> Result<String, OcrError> {
+    let mut lit = (0..image.height()).filter(|&row| image.row(row).contains(&true));
+    let top = lit
+        .next()
+        .ok_or_else(|| OcrError("there are no letters".to_string()))?;
+    let bottom = lit.next_back().unwrap_or(top);
+
+    match bottom - top + 1 {
+        6 => letters(image, top..=bottom, &SMALL),
+        10 => letters(image, top..=bottom, &LARGE),
+        height => Err(OcrError(format!(
+            "there is no font with letters that are {height} pixels high"
+        ))),
+    }
+}
+
+fn letters<const H: usize>(
+    image: &Grid<bool>,
+    rows: RangeInclusive<usize>,
+    font: &[(char, [&str; H])],
+) -> Result<String, OcrError> {
+    let is_lit = |col: usize| rows.clone().any(|row| image[(row, col)]);
+    let mut letters = String::new();
+    let mut col = 0;
+
+    while col < image.width() {
+        if !is_lit(col) {
+            col += 1;
+            continue;
+        }
+        let start = col;
+        while col < image.width() && is_lit(col) {
+            col += 1;
+        }
+
+        let glyph = rows
+            .clone()
+            .map(|row| {
+                (start..col)
+                    .map(|col| if image[(row, col)] { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        let (letter, _) = font
+            .iter()
+            .find(|(_, pattern)| pattern.iter().zip(&glyph).all(|(p, g)| p == g))
+            .ok_or_else(|| {
+                OcrError(format!(
+                    "unknown letter at column {start}:\n{}",
+                    glyph.join("\n")
+                ))
+            })?;
+        letters.push(*letter);
+    }
+
+    Ok(letters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_str() {
+        let picture = "
+            ####..##...##....##.
+            ...#.#..#.#..#....#.
+            ..#..#....#.......#.
+            .#...#.##.#.......#.
+            #....#..#.#..#.#..#.
+            ####..###..##...##..
+        ";
+        assert_eq!(read_str(picture), Ok("ZGCJ".to_string()));
+        assert!(read_str("##\n##").is_err());
+        assert!(read_str(&picture.replace("####..###", "####..#.#")).is_err());
+    }
+
+    #[test]
+    fn test_read_points() {
+        let letters = |letters: &[[&str; 10]]| {
+            let mut points = Vec::new();
+            for (i, letter) in letters.iter().enumerate() {
+                for (y, row) in letter.iter().enumerate() {
+                    for (x, _) in row.match_indices('#') {
+                        points.push(Point2::new([(8 * i + x) as i64 - 3, y as i64 + 7]));
+                    }
+                }
+            }
+            points
+        };
+        let (_, h) = LARGE[6];
+        let (_, x) = LARGE[13];
+
+        assert_eq!(read_points(letters(&[h, x, h])), Ok("HXH".to_string()));
+        assert!(read_points([]).is_err());
+    }
+}
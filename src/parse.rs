@@ -0,0 +1,266 @@
+use std::{
+    fmt::{Debug, Display},
+    iter::FusedIterator,
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use atoi::FromRadix10SignedChecked;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError(String);
+
+impl ParseError {
+    pub fn new<T: Into<String>>(message: T) -> Self {
+        Self(message.into())
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// All integers in `s`, in order. A `-` directly in front of a number
+/// is its sign, unless it follows a letter or digit as in `2-4`.
+pub fn ints<T>(s: &str) -> Ints<'_, T>
+where
+    T: FromRadix10SignedChecked,
+{
+    Ints {
+        s,
+        pos: 0,
+        _t: PhantomData,
+    }
+}
+
+/// Exactly `N` integers from `s`, e.g. `let [x, y] = ints_n(line)?;`.
+pub fn ints_n<T, const N: usize>(s: &str) -> Result<[T; N], ParseError>
+where
+    T: FromRadix10SignedChecked + Debug,
+{
+    let ints = ints(s).collect::<Result<Vec<T>, _>>()?;
+    let found = ints.len();
+    ints.try_into()
+        .map_err(|_| ParseError(format!("expected {N} numbers in `{s}`, found {found}")))
+}
+
+pub struct Ints<'a, T> {
+    s: &'a str,
+    pos: usize,
+    _t: PhantomData<T>,
+}
+
+impl<T> Iterator for Ints<'_, T>
+where
+    T: FromRadix10SignedChecked,
+{
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.s.as_bytes();
+        let digit = bytes[self.pos..].iter().position(u8::is_ascii_digit)?;
+        let mut start = self.pos + digit;
+
+        if start > 0 && bytes[start - 1] == b'-' {
+            let signed = start < 2 || !bytes[start - 2].is_ascii_alphanumeric();
+            if signed {
+                start -= 1;
+            }
+        }
+
+        let (value, used) = T::from_radix_10_signed_checked(&bytes[start..]);
+        self.pos = start + used;
+
+        Some(value.ok_or_else(|| {
+            ParseError(format!(
+                "number `{}` is out of range",
+                &self.s[start..self.pos]
+            ))
+        }))
+    }
+}
+
+impl<T> FusedIterator for Ints<'_, T> where T: FromRadix10SignedChecked {}
+
+/// Strips `keyword` and the whitespace around it from the start of `s`.
+pub fn keyword<'a>(s: &'a str, keyword: &str) -> Result<&'a str, ParseError> {
+    s.trim_start()
+        .strip_prefix(keyword)
+        .map(str::trim_start)
+        .ok_or_else(|| ParseError(format!("expected `{keyword}` at the start of `{s}`")))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token<'a> {
+    Word(&'a str),
+    Int(i64),
+    Symbol(char),
+}
+
+/// Splits `s` into words, integers and single symbols, skipping whitespace.
+pub fn tokens(s: &str) -> impl Iterator<Item = Result<Token<'_>, ParseError>> + '_ {
+    let mut rest = s;
+
+    std::iter::from_fn(move || {
+        rest = rest.trim_start();
+        let c = rest.chars().next()?;
+        let negative = c == '-' && rest[1..].starts_with(|c: char| c.is_ascii_digit());
+
+        let (token, len) = if c.is_ascii_digit() || negative {
+            let (value, used) = i64::from_radix_10_signed_checked(rest.as_bytes());
+            let token = value
+                .map(Token::Int)
+                .ok_or_else(|| ParseError(format!("number `{}` is out of range", &rest[..used])));
+            (token, used)
+        } else if c.is_alphabetic() || c == '_' {
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            (Ok(Token::Word(&rest[..len])), len)
+        } else {
+            (Ok(Token::Symbol(c)), c.len_utf8())
+        };
+
+        rest = &rest[len..];
+        Some(token)
+    })
+}
+
+/// Matches `s` against a `template` where every `{}` captures the text up to
+/// the following literal part; used by [`scan!`](crate::scan).
+pub fn scan<'a>(s: &'a str, template: &str, fields: usize) -> Result<Vec<&'a str>, ParseError> {
+    let mismatch = || ParseError(format!("`{s}` does not match `{template}`"));
+
+    let mut parts = template.split("{}");
+    let mut rest = s
+        .strip_prefix(parts.next().unwrap_or_default())
+        .ok_or_else(mismatch)?;
+    let mut captures = Vec::with_capacity(fields);
+
+    for literal in parts {
+        let end = if literal.is_empty() {
+            rest.len()
+        } else {
+            rest.find(literal).ok_or_else(mismatch)?
+        };
+        captures.push(&rest[..end]);
+        rest = &rest[end + literal.len()..];
+    }
+
+    if !rest.is_empty() {
+        return Err(mismatch());
+    }
+    if captures.len() != fields {
+        return Err(ParseError(format!(
+            "`{template}` has {} fields, expected {fields}",
+            captures.len()
+        )));
+    }
+
+    Ok(captures)
+}
+
+/// Parses a single field captured by [`scan!`](crate::scan).
+pub fn field<T>(s: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Debug,
+{
+    s.trim()
+        .parse()
+        .map_err(|e| ParseError(format!("cannot parse `{s}`: {e:?}")))
+}
+
+/// Parses `input` according to a template of literal text and `{}` fields,
+/// returning a tuple with one value per field:
+///
+/// ```
+/// let (count, from, to) = aoc::scan!("move 3 from 1 to 2", "move {} from {} to {}" => usize, u8, u8)?;
+/// assert_eq!((count, from, to), (3, 1, 2));
+/// # Ok::<_, aoc::parse::ParseError>(())
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($input:expr, $template:expr => $($ty:ty),+ $(,)?) => {
+        $crate::parse::scan($input, $template, [$(stringify!($ty)),+].len()).and_then(|fields| {
+            let mut fields = fields.into_iter();
+            Ok(($(
+                $crate::parse::field::<$ty>(fields.next().unwrap_or_default())?,
+            )+))
+        })
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        let line = "Sensor at x=2, y=-18: beacon at x=-2, y=15";
+        assert_eq!(
+            ints::<i32>(line).collect::<Result<Vec<_>, _>>(),
+            Ok(vec![2, -18, -2, 15])
+        );
+        assert_eq!(
+            ints::<u8>("2-4,6-8").collect::<Result<Vec<_>, _>>(),
+            Ok(vec![2, 4, 6, 8])
+        );
+        assert_eq!(
+            ints_n::<i64, 2>("0,9 -> 5,9").unwrap_err().to_string(),
+            "expected 2 numbers in `0,9 -> 5,9`, found 4"
+        );
+        assert_eq!(ints_n("x=20..30, y=-10..-5"), Ok([20, 30, -10, -5]));
+        assert_eq!(
+            ints::<u8>("7 300").collect::<Vec<_>>(),
+            [
+                Ok(7),
+                Err(ParseError("number `300` is out of range".to_string()))
+            ]
+        );
+        assert!(ints::<u32>("a -1").next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_tokens() {
+        let tokens = tokens("Game 12: 3 blue, -4 red").collect::<Result<Vec<_>, _>>();
+        assert_eq!(
+            tokens,
+            Ok(vec![
+                Token::Word("Game"),
+                Token::Int(12),
+                Token::Symbol(':'),
+                Token::Int(3),
+                Token::Word("blue"),
+                Token::Symbol(','),
+                Token::Int(-4),
+                Token::Word("red"),
+            ])
+        );
+        assert_eq!(keyword("  Game 12", "Game"), Ok("12"));
+        assert!(keyword("Card 1", "Game").is_err());
+    }
+
+    #[test]
+    fn test_scan() {
+        let target = scan!(
+            "target area: x=20..30, y=-10..-5",
+            "target area: x={}..{}, y={}..{}" => i32, i32, i32, i32
+        );
+        assert_eq!(target, Ok((20, 30, -10, -5)));
+
+        let line = scan!("move 3 from 1 to 2", "move {} from {} to {}" => usize, u8, u8);
+        assert_eq!(line, Ok((3, 1, 2)));
+
+        let name = scan!("Valve AA has flow rate=0", "Valve {} has flow rate={}" => String, u32);
+        assert_eq!(name, Ok(("AA".to_string(), 0)));
+
+        assert!(scan!("move 3 to 2", "move {} from {} to {}" => u8, u8, u8).is_err());
+        assert!(scan!("move x from 1 to 2", "move {} from {} to {}" => u8, u8, u8).is_err());
+        assert!(scan!("move 1 from 1 to 2", "move {} from {} to {}" => u8, u8).is_err());
+    }
+}
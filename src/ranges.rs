@@ -0,0 +1,240 @@
+use std::{
+    fmt::Debug,
+    iter::Sum,
+    ops::{Add, Range, RangeInclusive, Sub},
+};
+
+/// An integer that can be used as the end of an interval.
+pub trait Endpoint: Copy + Ord + Debug + Add<Output = Self> + Sub<Output = Self> + Sum {
+    const ONE: Self;
+}
+
+macro_rules! endpoint {
+    ($($t:ty),*) => {
+        $(
+            impl Endpoint for $t {
+                const ONE: Self = 1;
+            }
+        )*
+    };
+}
+
+endpoint!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A set of integers stored as sorted, disjoint, half-open ranges.
+///
+/// Touching ranges are merged, so e.g. `1..3` and `3..5` become `1..5`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let lo = self.ranges.partition_point(|r| r.end < range.start);
+        let hi = self.ranges.partition_point(|r| r.start <= range.end);
+
+        let merged = if lo < hi {
+            self.ranges[lo].start.min(range.start)..self.ranges[hi - 1].end.max(range.end)
+        } else {
+            range
+        };
+        self.ranges.splice(lo..hi, [merged]);
+    }
+
+    pub fn insert_inclusive(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start <= end {
+            self.insert(start..end + T::ONE);
+        }
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of integers in the set.
+    pub fn len(&self) -> T {
+        self.ranges.iter().map(|r| r.end - r.start).sum()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    /// Whether every integer in `range` is part of the set.
+    pub fn contains_range(&self, range: Range<T>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+        let i = self.ranges.partition_point(|r| r.end < range.end);
+        self.ranges.get(i).is_some_and(|r| r.start <= range.start)
+    }
+
+    /// Whether any integer in `range` is part of the set.
+    pub fn overlaps(&self, range: Range<T>) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= range.start);
+        self.ranges
+            .get(i)
+            .is_some_and(|r| r.start < range.end && !range.is_empty())
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        other.ranges.iter().for_each(|r| union.insert(r.clone()));
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let start = x.start.max(y.start);
+            let end = x.end.min(y.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut others = other.ranges.iter().peekable();
+
+        for range in &self.ranges {
+            let mut start = range.start;
+            // skip everything that ends before this range
+            while others.next_if(|o| o.end <= start).is_some() {}
+            while let Some(o) = others.peek() {
+                if o.start >= range.end {
+                    break;
+                }
+                if start < o.start {
+                    ranges.push(start..o.start);
+                }
+                start = start.max(o.end);
+                if o.end > range.end {
+                    break;
+                }
+                others.next();
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// The maximal ranges inside `within` that are not part of the set.
+    pub fn gaps(&self, within: Range<T>) -> impl Iterator<Item = Range<T>> {
+        Self::from(within).difference(self).ranges.into_iter()
+    }
+}
+
+impl<T: Endpoint> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::from_iter([range])
+    }
+}
+
+impl<T: Endpoint> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        Self::from_iter([range])
+    }
+}
+
+impl<T: Endpoint> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        iter.into_iter().for_each(|r| set.insert(r));
+        set
+    }
+}
+
+impl<T: Endpoint> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        iter.into_iter().for_each(|r| set.insert_inclusive(r));
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut set = IntervalSet::new();
+        set.insert(10..12);
+        set.insert(1..3);
+        set.insert(5..7);
+        set.insert(3..4);
+        set.insert(8..8);
+        assert_eq!(set.ranges(), [1..4, 5..7, 10..12]);
+
+        set.insert(6..11);
+        assert_eq!(set.ranges(), [1..4, 5..12]);
+        set.insert_inclusive(0..=4);
+        assert_eq!(set, IntervalSet::from(0..12));
+        assert_eq!(set.len(), 12);
+    }
+
+    #[test]
+    fn test_queries() {
+        let set = IntervalSet::from_iter([-5..=-1, 3..=5]);
+        assert_eq!(set.len(), 8);
+        assert!(set.contains(-5) && set.contains(-1) && set.contains(5));
+        assert!(!set.contains(0) && !set.contains(6));
+        assert!(set.contains_range(3..6));
+        assert!(!set.contains_range(-1..4));
+        assert!(set.overlaps(-1..4));
+        assert!(!set.overlaps(0..3));
+        assert_eq!(
+            set.gaps(-10..10).collect::<Vec<_>>(),
+            [-10..-5, 0..3, 6..10]
+        );
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = IntervalSet::from_iter([0..10, 20..30]);
+        let b = IntervalSet::from_iter([5..25, 28..29]);
+
+        assert_eq!(a.union(&b), IntervalSet::from(0..30));
+        assert_eq!(a.intersection(&b).ranges(), [5..10, 20..25, 28..29]);
+        assert_eq!(a.difference(&b).ranges(), [0..5, 25..28, 29..30]);
+        assert_eq!(b.difference(&a), IntervalSet::from(10..20));
+        assert!(a.difference(&a).is_empty());
+    }
+}
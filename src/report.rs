@@ -0,0 +1,332 @@
+use std::{
+    fmt::{Display, Write},
+    time::Duration,
+};
+
+use crate::{answers::Verdict, bench::Stats};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub value: String,
+    pub time: Duration,
+    pub stats: Option<Stats>,
+    pub verdict: Verdict,
+}
+
+impl Answer {
+    pub fn new<T: Display + ?Sized>(value: &T, time: Duration, verdict: Verdict) -> Self {
+        Self {
+            value: value.to_string(),
+            time,
+            stats: None,
+            verdict,
+        }
+    }
+}
+
+/// The outcome of running a single day, independent of how it is printed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub parse_time: Option<Duration>,
+    pub parse_stats: Option<Stats>,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn new(year: u16, day: u8) -> Self {
+        Self {
+            year,
+            day,
+            parse_time: None,
+            parse_stats: None,
+            part1: None,
+            part2: None,
+            error: None,
+        }
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.parse_time.unwrap_or_default()
+            + [&self.part1, &self.part2]
+                .into_iter()
+                .flatten()
+                .map(|answer| answer.time)
+                .sum::<Duration>()
+    }
+}
+
+/// Renders records as a JSON array with one object per day.
+///
+/// Durations are given in nanoseconds, answers are always strings
+/// as they can be arbitrarily large numbers or multi-line text.
+/// Benchmark runs add `*_stats` objects next to the median timings.
+pub fn json(records: &[Record]) -> String {
+    fn answer(out: &mut String, name: &str, answer: Option<&Answer>) {
+        match answer {
+            Some(answer) => {
+                write!(
+                    out,
+                    r#", "{name}": {}, "{name}_ns": {}, "{name}_verdict": "{}""#,
+                    json_string(&answer.value),
+                    answer.time.as_nanos(),
+                    answer.verdict
+                )
+                .unwrap();
+                stats(out, name, answer.stats);
+            }
+            None => write!(
+                out,
+                r#", "{name}": null, "{name}_ns": null, "{name}_verdict": null"#
+            )
+            .unwrap(),
+        }
+    }
+
+    fn stats(out: &mut String, name: &str, stats: Option<Stats>) {
+        if let Some(stats) = stats {
+            write!(
+                out,
+                r#", "{name}_stats": {{"runs": {}, "min_ns": {}, "median_ns": {}, "mean_ns": {}, "stddev_ns": {}}}"#,
+                stats.runs,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos()
+            )
+            .unwrap();
+        }
+    }
+
+    let mut out = String::from("[");
+
+    for (i, record) in records.iter().enumerate() {
+        out.push_str(if i == 0 { "\n  " } else { ",\n  " });
+        write!(
+            out,
+            r#"{{"year": {}, "day": {}, "parse_ns": {}"#,
+            record.year,
+            record.day,
+            record
+                .parse_time
+                .map_or_else(|| "null".to_string(), |d| d.as_nanos().to_string())
+        )
+        .unwrap();
+        stats(&mut out, "parse", record.parse_stats);
+        answer(&mut out, "part1", record.part1.as_ref());
+        answer(&mut out, "part2", record.part2.as_ref());
+        write!(
+            out,
+            r#", "error": {}}}"#,
+            record
+                .error
+                .as_deref()
+                .map_or_else(|| "null".to_string(), json_string)
+        )
+        .unwrap();
+    }
+
+    out.push_str(if records.is_empty() { "]" } else { "\n]" });
+    out
+}
+
+/// Renders records as CSV with a header row, following RFC 4180 quoting.
+///
+/// The `*_min_ns`, `*_median_ns`, `*_mean_ns` and `*_stddev_ns` columns
+/// are only filled for benchmark runs.
+pub fn csv(records: &[Record]) -> String {
+    let mut out = String::from("year,day,parse_ns");
+    csv_stats_header(&mut out, "parse");
+    for part in ["part1", "part2"] {
+        write!(out, ",{part},{part}_ns,{part}_verdict").unwrap();
+        csv_stats_header(&mut out, part);
+    }
+    out.push_str(",error\n");
+
+    for record in records {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{}",
+            record.year,
+            record.day,
+            record
+                .parse_time
+                .map(|d| d.as_nanos().to_string())
+                .unwrap_or_default(),
+            csv_stats(record.parse_stats),
+            csv_answer(record.part1.as_ref()),
+            csv_answer(record.part2.as_ref()),
+            csv_field(record.error.as_deref().unwrap_or_default()),
+        )
+        .unwrap();
+    }
+
+    out
+}
+
+fn csv_stats_header(out: &mut String, name: &str) {
+    for stat in ["min", "median", "mean", "stddev"] {
+        write!(out, ",{name}_{stat}_ns").unwrap();
+    }
+}
+
+fn csv_stats(stats: Option<Stats>) -> String {
+    stats.map_or_else(
+        || ",,,".to_string(),
+        |stats| {
+            format!(
+                "{},{},{},{}",
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos()
+            )
+        },
+    )
+}
+
+fn csv_answer(answer: Option<&Answer>) -> String {
+    answer.map_or_else(
+        || ",,,,,,".to_string(),
+        |answer| {
+            format!(
+                "{},{},{},{}",
+                csv_field(&answer.value),
+                answer.time.as_nanos(),
+                answer.verdict,
+                csv_stats(answer.stats)
+            )
+        },
+    )
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                parse_time: Some(Duration::from_nanos(1200)),
+                part1: Some(Answer::new(
+                    &42,
+                    Duration::from_nanos(300),
+                    Verdict::Correct,
+                )),
+                part2: Some(Answer::new(
+                    "#.\n.#",
+                    Duration::from_micros(2),
+                    Verdict::Unknown,
+                )),
+                ..Record::new(2022, 10)
+            },
+            Record {
+                error: Some(r#"line 3: cannot parse "x""#.to_string()),
+                ..Record::new(2022, 11)
+            },
+        ]
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            json(&records()),
+            r##"[
+  {"year": 2022, "day": 10, "parse_ns": 1200, "part1": "42", "part1_ns": 300, "part1_verdict": "correct", "part2": "#.\n.#", "part2_ns": 2000, "part2_verdict": "unknown", "error": null},
+  {"year": 2022, "day": 11, "parse_ns": null, "part1": null, "part1_ns": null, "part1_verdict": null, "part2": null, "part2_ns": null, "part2_verdict": null, "error": "line 3: cannot parse \"x\""}
+]"##
+        );
+        assert_eq!(json(&[]), "[]");
+    }
+
+    #[test]
+    fn test_json_stats() {
+        let stats = Stats::new(vec![Duration::from_nanos(10), Duration::from_nanos(30)]);
+        let record = Record {
+            parse_time: Some(stats.median),
+            parse_stats: Some(stats),
+            ..Record::new(2021, 1)
+        };
+
+        assert_eq!(
+            json(&[record]),
+            r#"[
+  {"year": 2021, "day": 1, "parse_ns": 30, "parse_stats": {"runs": 2, "min_ns": 10, "median_ns": 30, "mean_ns": 20, "stddev_ns": 10}, "part1": null, "part1_ns": null, "part1_verdict": null, "part2": null, "part2_ns": null, "part2_verdict": null, "error": null}
+]"#
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            csv(&records()),
+            r##"year,day,parse_ns,parse_min_ns,parse_median_ns,parse_mean_ns,parse_stddev_ns,part1,part1_ns,part1_verdict,part1_min_ns,part1_median_ns,part1_mean_ns,part1_stddev_ns,part2,part2_ns,part2_verdict,part2_min_ns,part2_median_ns,part2_mean_ns,part2_stddev_ns,error
+2022,10,1200,,,,,42,300,correct,,,,,"#.
+.#",2000,unknown,,,,,
+2022,11,,,,,,,,,,,,,,,,,,,,"line 3: cannot parse ""x"""
+"##
+        );
+    }
+
+    #[test]
+    fn test_csv_stats() {
+        let stats = Stats::new(vec![Duration::from_nanos(10), Duration::from_nanos(30)]);
+        let record = Record {
+            parse_time: Some(stats.median),
+            parse_stats: Some(stats),
+            part1: Some(Answer {
+                stats: Some(stats),
+                ..Answer::new(&1, stats.median, Verdict::Correct)
+            }),
+            ..Record::new(2021, 1)
+        };
+
+        assert_eq!(
+            csv(&[record]).lines().nth(1),
+            Some("2021,1,30,10,30,20,10,1,30,correct,10,30,20,10,,,,,,,,")
+        );
+    }
+
+    #[test]
+    fn test_total_time() {
+        assert_eq!(records()[0].total_time(), Duration::from_nanos(3500));
+        assert_eq!(records()[1].total_time(), Duration::ZERO);
+    }
+}
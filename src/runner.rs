@@ -0,0 +1,487 @@
+use std::{borrow::Cow, fmt::Display, fs, path::Path, process::ExitCode, time::Duration};
+
+use fxhash::FxHashMap;
+
+use crate::{
+    animate,
+    answers::{Answers, Verdict},
+    baseline::Baseline,
+    bench::{self, Stats},
+    cli::{Args, DayList, Part},
+    log,
+    report::{self, Answer, Format, Record},
+    span::{self, Span},
+    timed, viz, InputError, ResultLine, Solution,
+};
+
+/// A parsed day with the answers and timings of the parts that were run.
+pub struct Solved {
+    pub parse_time: Duration,
+    pub parts: [Option<SolvedPart>; 2],
+}
+
+/// The answer of a part with its time and the spans entered while solving it.
+pub struct SolvedPart {
+    pub answer: Box<dyn Display>,
+    pub time: Duration,
+    pub spans: Vec<Span>,
+}
+
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    file: &'static str,
+    embedded: &'static str,
+    solve: fn(&str, Option<Part>) -> Result<Solved, InputError>,
+}
+
+impl Entry {
+    pub fn input(&self, args: &Args) -> std::io::Result<Cow<'static, str>> {
+        args.input(self.year, self.day, self.file, self.embedded)
+    }
+
+    /// Parses the input and runs the given part, or both parts if `None`.
+    pub fn solve(&self, input: &str, part: Option<Part>) -> Result<Solved, InputError> {
+        (self.solve)(input, part)
+    }
+}
+
+#[derive(Default)]
+pub struct Registry {
+    entries: Vec<Entry>,
+    answers: Vec<(u16, &'static str)>,
+}
+
+impl Registry {
+    pub fn register<S>(&mut self, year: u16, day: u8)
+    where
+        S: Solution,
+        S::Output1: Display + 'static,
+        S::Output2: Display + 'static,
+    {
+        fn run<I, T: Display + 'static>(input: &I, f: fn(&I) -> T) -> SolvedPart {
+            let ((answer, time), spans) = span::collect(|| timed(|| f(input)));
+            SolvedPart {
+                answer: Box::new(answer),
+                time,
+                spans,
+            }
+        }
+
+        fn solve<S>(input: &str, part: Option<Part>) -> Result<Solved, InputError>
+        where
+            S: Solution,
+            S::Output1: Display + 'static,
+            S::Output2: Display + 'static,
+        {
+            let (input, parse_time) = timed(|| S::parse_input(input));
+            let input = input?;
+            let runs = |p: Part| part.is_none_or(|part| part == p);
+
+            Ok(Solved {
+                parse_time,
+                parts: [
+                    runs(Part::One).then(|| run(&input, S::part1)),
+                    runs(Part::Two).then(|| run(&input, S::part2)),
+                ],
+            })
+        }
+
+        self.entries.push(Entry {
+            year,
+            day,
+            file: S::puzzle_file(),
+            embedded: S::puzzle_input(),
+            solve: solve::<S>,
+        });
+        self.entries.sort_by_key(|e| (e.year, e.day));
+    }
+
+    pub fn register_answers(&mut self, year: u16, answers: &'static str) {
+        self.answers.push((year, answers));
+    }
+
+    pub fn answers(&self, year: u16) -> &'static str {
+        self.answers
+            .iter()
+            .find(|(y, _)| *y == year)
+            .map_or("", |(_, answers)| answers)
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&Entry> {
+        self.entries.iter().find(|e| e.year == year && e.day == day)
+    }
+
+    pub fn puzzles(&self) -> Vec<(u16, u8)> {
+        self.entries.iter().map(|e| (e.year, e.day)).collect()
+    }
+}
+
+pub fn main(bin: &str, registry: &Registry) -> ExitCode {
+    let usage = Args::usage(bin);
+
+    let args = match Args::from_env() {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("{error}\n\n{usage}");
+            return ExitCode::from(2);
+        }
+    };
+
+    if args.help {
+        println!("{usage}");
+        return ExitCode::SUCCESS;
+    }
+
+    if args.list {
+        for puzzles in registry.puzzles().chunk_by(|a, b| a.0 == b.0) {
+            let days = puzzles.iter().map(|(_, day)| *day).collect::<Vec<_>>();
+            println!("{}: {}", puzzles[0].0, DayList(&days));
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    if let Some(dir) = &args.record {
+        if let Err(error) = viz::record_to(dir, args.record_format) {
+            eprintln!("{error}");
+            return ExitCode::from(2);
+        }
+    }
+
+    if let Some(level) = args.log_level() {
+        if let Err(error) = log::init(level, args.log_targets.clone()) {
+            eprintln!("{error}");
+            return ExitCode::from(2);
+        }
+    }
+
+    if args.animate {
+        if let Err(error) = viz::animate() {
+            eprintln!("{error}");
+            return ExitCode::from(2);
+        }
+    }
+
+    let puzzles = match args.puzzles(&registry.puzzles()) {
+        Ok(puzzles) => puzzles,
+        Err(error) => {
+            eprintln!("{error}\n\n{usage}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let answers = match load_answers(&args, registry, &puzzles) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let baseline = match args.baseline.as_deref().map(load_baseline).transpose() {
+        Ok(baseline) => baseline,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut records = Vec::with_capacity(puzzles.len());
+    let mut failed = false;
+    let mut incorrect = 0;
+
+    for (year, day) in puzzles {
+        let entry = registry
+            .get(year, day)
+            .expect("puzzles are validated against the registry");
+
+        let mut record = Record::new(year, day);
+
+        let lines = match run(entry, &args, &answers) {
+            Ok(Run {
+                solution,
+                stats,
+                answers,
+            }) => {
+                let (parse_stats, part_stats) = match stats {
+                    Some((parse, parts)) => (Some(parse), parts),
+                    None => (None, [None; 2]),
+                };
+
+                let parse_time = parse_stats.map_or(solution.parse_time, |s| s.median);
+                record.parse_time = Some(parse_time);
+                record.parse_stats = parse_stats;
+
+                let mut line = ResultLine::note("Parsing", parse_time);
+                if let Some(stats) = parse_stats {
+                    line = line.stats(stats);
+                }
+                let mut lines = vec![line];
+
+                for ((part, solved), stats) in (1..).zip(solution.parts).zip(part_stats) {
+                    let Some(SolvedPart {
+                        answer,
+                        time,
+                        spans,
+                    }) = solved
+                    else {
+                        continue;
+                    };
+
+                    let time = stats.map_or(time, |s| s.median);
+                    let verdict = answers.map_or(Verdict::Unknown, |a| a.check(day, part, &answer));
+                    if matches!(verdict, Verdict::Incorrect { .. }) {
+                        incorrect += 1;
+                    }
+
+                    let result = Some(Answer {
+                        stats,
+                        ..Answer::new(&answer, time, verdict.clone())
+                    });
+                    if part == 1 {
+                        record.part1 = result;
+                    } else {
+                        record.part2 = result;
+                    }
+
+                    let mut line = ResultLine::solution(part, time, answer)
+                        .verdict(verdict)
+                        .spans(spans);
+                    if let Some(stats) = stats {
+                        line = line.stats(stats);
+                    }
+                    lines.push(line);
+                }
+                lines.push(ResultLine::note("Total", record.total_time()));
+
+                lines
+            }
+            Err((stage, error)) => {
+                failed = true;
+                record.error = Some(error.clone());
+                vec![ResultLine::failure(stage, error)]
+            }
+        };
+
+        for clip in viz::take_clips() {
+            if let Err(error) = animate::play(&clip, &args.animation()) {
+                eprintln!("failed to play {}: {error}", clip.name);
+            }
+        }
+
+        if !args.quiet && args.format == Format::Table {
+            println!("{year} Day {day:02}");
+            for line in lines {
+                println!("  - {line}");
+            }
+            println!();
+        }
+
+        records.push(record);
+    }
+
+    if !args.quiet {
+        match args.format {
+            Format::Table => print_total(&records),
+            Format::Json => println!("{}", report::json(&records)),
+            Format::Csv => print!("{}", report::csv(&records)),
+        }
+    }
+
+    if let Some(baseline) = &baseline {
+        let regressions = compare(&args, baseline, &records);
+        if regressions > 0 {
+            eprintln!(
+                "{regressions} day{} slower than the baseline by more than {}%",
+                if regressions == 1 { " is" } else { "s are" },
+                args.threshold()
+            );
+            failed = true;
+        }
+    }
+
+    if let Some(path) = &args.save_baseline {
+        if let Err(error) = save_baseline(path, &records) {
+            eprintln!("{error}");
+            failed = true;
+        }
+    }
+
+    if args.verify && incorrect > 0 {
+        eprintln!(
+            "{incorrect} incorrect answer{}",
+            if incorrect == 1 { "" } else { "s" }
+        );
+        failed = true;
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+type PartStats = (Stats, [Option<Stats>; 2]);
+
+struct Run<'a> {
+    solution: Solved,
+    stats: Option<PartStats>,
+    answers: Option<&'a Answers>,
+}
+
+fn run<'a>(
+    entry: &Entry,
+    args: &Args,
+    answers: &'a AnswerBook,
+) -> Result<Run<'a>, (&'static str, String)> {
+    let input = entry.input(args).map_err(|e| ("Input", e.to_string()))?;
+    let answers = answers.get(entry.year, matches!(input, Cow::Borrowed(_)));
+    let solution = entry
+        .solve(&input, args.part)
+        .map_err(|e| ("Parsing", e.to_string()))?;
+    let stats = args
+        .bench
+        .map(|runs| bench(entry, &input, args.part, runs))
+        .transpose()
+        .map_err(|e| ("Parsing", e.to_string()))?;
+
+    Ok(Run {
+        solution,
+        stats,
+        answers,
+    })
+}
+
+/// Returns the parsing and per-part stats over `runs` repeated solves.
+fn bench(
+    entry: &Entry,
+    input: &str,
+    part: Option<Part>,
+    runs: usize,
+) -> Result<PartStats, InputError> {
+    for _ in 0..bench::warmup(runs) {
+        entry.solve(input, part)?;
+    }
+
+    let mut parse = Vec::with_capacity(runs);
+    let mut parts = [(); 2].map(|()| Vec::with_capacity(runs));
+
+    for _ in 0..runs {
+        let solution = entry.solve(input, part)?;
+        parse.push(solution.parse_time);
+        for (samples, solved) in parts.iter_mut().zip(solution.parts) {
+            if let Some(solved) = solved {
+                samples.push(solved.time);
+            }
+        }
+    }
+
+    let parts = parts.map(|samples| (!samples.is_empty()).then(|| Stats::new(samples)));
+    Ok((Stats::new(parse), parts))
+}
+
+/// Known answers per year, both for the embedded inputs
+/// and for inputs read from `--input-dir`.
+#[derive(Default)]
+struct AnswerBook {
+    embedded: FxHashMap<u16, Answers>,
+    external: FxHashMap<u16, Answers>,
+}
+
+impl AnswerBook {
+    fn get(&self, year: u16, embedded: bool) -> Option<&Answers> {
+        if embedded {
+            self.embedded.get(&year)
+        } else {
+            self.external.get(&year)
+        }
+    }
+}
+
+fn load_answers(
+    args: &Args,
+    registry: &Registry,
+    puzzles: &[(u16, u8)],
+) -> Result<AnswerBook, String> {
+    let mut book = AnswerBook::default();
+
+    for &(year, _) in puzzles {
+        if book.embedded.contains_key(&year) {
+            continue;
+        }
+
+        let embedded = Answers::parse(registry.answers(year))
+            .map_err(|e| format!("invalid answers for {year}: {e}"))?;
+        book.embedded.insert(year, embedded);
+
+        if let Some(answers) = args
+            .answers(year)
+            .map_err(|e| format!("cannot read answers for {year}: {e}"))?
+        {
+            let external =
+                Answers::parse(&answers).map_err(|e| format!("invalid answers for {year}: {e}"))?;
+            book.external.insert(year, external);
+        }
+    }
+
+    Ok(book)
+}
+
+fn load_baseline(path: &Path) -> Result<Baseline, String> {
+    let baseline = fs::read_to_string(path)
+        .map_err(|e| format!("cannot read baseline {}: {e}", path.display()))?;
+    Baseline::parse(&baseline).map_err(|e| format!("invalid baseline {}: {e}", path.display()))
+}
+
+/// Updates the baseline at `path` with the timings of the solved days.
+fn save_baseline(path: &Path, records: &[Record]) -> Result<(), String> {
+    let mut baseline = if path.exists() {
+        load_baseline(path)?
+    } else {
+        Baseline::default()
+    };
+    baseline.update(records);
+    fs::write(path, baseline.to_string())
+        .map_err(|e| format!("cannot write baseline {}: {e}", path.display()))
+}
+
+/// Prints the per-day deltas and returns the number of regressions.
+fn compare(args: &Args, baseline: &Baseline, records: &[Record]) -> usize {
+    let threshold = args.threshold();
+    let deltas = baseline.compare(records);
+
+    if !args.quiet && args.format == Format::Table && !deltas.is_empty() {
+        println!();
+        println!("Compared to baseline (threshold {threshold}%):");
+        for delta in &deltas {
+            let note = format!("{} Day {:02}", delta.year, delta.day);
+            let line = ResultLine::value(&note, delta.after, *delta);
+            println!("  - {}", line.failed(delta.regressed(threshold)));
+        }
+    }
+
+    deltas
+        .iter()
+        .filter(|delta| delta.regressed(threshold))
+        .count()
+}
+
+fn print_total(records: &[Record]) {
+    let year_times = records
+        .chunk_by(|a, b| a.year == b.year)
+        .map(|records| {
+            let time = records.iter().map(Record::total_time).sum::<Duration>();
+            (records[0].year, time)
+        })
+        .collect::<Vec<_>>();
+
+    let total_time = year_times.iter().map(|(_, time)| *time).sum::<Duration>();
+    println!("Total time: {}", humantime::format_duration(total_time));
+
+    if year_times.len() > 1 {
+        for (year, time) in year_times {
+            println!("  - {}", ResultLine::note(&year, time));
+        }
+    }
+}
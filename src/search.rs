@@ -0,0 +1,230 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use fxhash::FxHashMap;
+
+/// The cheapest route found by a search, from one of the starts to the goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+impl<N, C> Path<N, C> {
+    pub fn start(&self) -> &N {
+        &self.nodes[0]
+    }
+
+    pub fn goal(&self) -> &N {
+        &self.nodes[self.nodes.len() - 1]
+    }
+}
+
+/// Every discovered node, together with the node it was reached from.
+struct Tree<N, C> {
+    nodes: Vec<(N, Option<usize>, C)>,
+    index: FxHashMap<N, usize>,
+}
+
+impl<N, C> Tree<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy,
+{
+    fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            index: FxHashMap::default(),
+        }
+    }
+
+    /// Records `node` unless it was discovered before.
+    fn insert(&mut self, node: N, parent: Option<usize>, cost: C) -> Option<usize> {
+        match self.index.entry(node) {
+            Entry::Occupied(_) => None,
+            Entry::Vacant(entry) => {
+                let idx = self.nodes.len();
+                self.nodes.push((entry.key().clone(), parent, cost));
+                entry.insert(idx);
+                Some(idx)
+            }
+        }
+    }
+
+    /// Records `node`, or reroutes it if `cost` is cheaper than before.
+    fn relax(&mut self, node: N, parent: usize, cost: C) -> Option<usize>
+    where
+        C: Ord,
+    {
+        match self.index.get(&node) {
+            Some(&idx) if cost < self.nodes[idx].2 => {
+                self.nodes[idx].1 = Some(parent);
+                self.nodes[idx].2 = cost;
+                Some(idx)
+            }
+            Some(_) => None,
+            None => self.insert(node, Some(parent), cost),
+        }
+    }
+
+    fn path(&self, mut idx: usize) -> Path<N, C> {
+        let cost = self.nodes[idx].2;
+        let mut nodes = vec![self.nodes[idx].0.clone()];
+        while let Some(parent) = self.nodes[idx].1 {
+            nodes.push(self.nodes[parent].0.clone());
+            idx = parent;
+        }
+        nodes.reverse();
+        Path { cost, nodes }
+    }
+}
+
+/// Breadth-first search from all `starts` at once, stopping at the
+/// first node for which `goal` holds. The cost is the number of steps.
+pub fn bfs<N, S, I, G>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: S,
+    mut goal: G,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut tree = Tree::new();
+    let mut queue = starts
+        .into_iter()
+        .filter_map(|start| tree.insert(start, None, 0))
+        .collect::<VecDeque<_>>();
+
+    while let Some(idx) = queue.pop_front() {
+        let (node, _, steps) = &tree.nodes[idx];
+        if goal(node) {
+            return Some(tree.path(idx));
+        }
+        let steps = steps + 1;
+        for next in successors(node) {
+            queue.extend(tree.insert(next, Some(idx), steps));
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm from all `starts` at once. `successors`
+/// yields each neighbor along with the cost of moving there.
+pub fn dijkstra<N, C, S, I, G>(
+    starts: impl IntoIterator<Item = N>,
+    successors: S,
+    goal: G,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(starts, successors, |_| C::default(), goal)
+}
+
+/// A* search, where `heuristic` must never overestimate
+/// the remaining cost for the result to be the cheapest path.
+pub fn astar<N, C, S, I, H, G>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: S,
+    mut heuristic: H,
+    mut goal: G,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut tree = Tree::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(idx) = tree.insert(start, None, C::default()) {
+            heap.push(Reverse((estimate, C::default(), idx)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, idx))) = heap.pop() {
+        let (node, _, best) = &tree.nodes[idx];
+        // already reached through a cheaper path
+        if cost > *best {
+            continue;
+        }
+        if goal(node) {
+            return Some(tree.path(idx));
+        }
+        for (next, step) in successors(node) {
+            let next_cost = cost + step;
+            let estimate = next_cost + heuristic(&next);
+            if let Some(next) = tree.relax(next, idx, next_cost) {
+                heap.push(Reverse((estimate, next_cost, next)));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -1-> 1 -1-> 2 -1-> 3
+    //  \_________5_______/
+    fn successors(&node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (3, 5)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let unweighted = |node: &u32| successors(node).into_iter().map(|(next, _)| next);
+
+        let path = bfs([0], unweighted, |&n| n == 3).unwrap();
+        assert_eq!(path.cost, 1);
+        assert_eq!(path.nodes, [0, 3]);
+
+        let path = bfs([2, 0], unweighted, |&n| n == 3).unwrap();
+        assert_eq!((path.start(), path.goal()), (&2, &3));
+
+        assert_eq!(bfs([3], unweighted, |&n| n == 0), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra([0], successors, |&n| n == 3).unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes, [0, 1, 2, 3]);
+
+        let path = dijkstra([0, 2], successors, |&n| n == 3).unwrap();
+        assert_eq!(path.nodes, [2, 3]);
+
+        assert_eq!(dijkstra([1], successors, |&n| n == 0), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        let path = astar([0], successors, |&n| 3 - n, |&n| n == 3).unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes, [0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,132 @@
+//! Hierarchical timing of the steps inside a part, see [`span!`](crate::span!).
+//!
+//! Spans are only recorded inside [`collect`], which the runner wraps
+//! around each part. Elsewhere, e.g. in tests, entering a span does not
+//! read the clock.
+
+use std::{
+    cell::RefCell,
+    time::{Duration, Instant},
+};
+
+/// The total time spent in all spans with the same name and parent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub name: &'static str,
+    pub time: Duration,
+    pub calls: usize,
+    pub children: Vec<Span>,
+}
+
+impl Span {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            time: Duration::ZERO,
+            calls: 0,
+            children: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, span: Self) {
+        match self.children.iter_mut().find(|c| c.name == span.name) {
+            Some(child) => {
+                child.time += span.time;
+                child.calls += span.calls;
+                span.children.into_iter().for_each(|c| child.add(c));
+            }
+            None => self.children.push(span),
+        }
+    }
+}
+
+thread_local! {
+    /// The open spans, starting with an unnamed root, while collecting.
+    static STACK: RefCell<Option<Vec<Span>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` and returns the spans that were entered within it.
+pub fn collect<T>(f: impl FnOnce() -> T) -> (T, Vec<Span>) {
+    let outer = STACK.replace(Some(vec![Span::new("")]));
+    let res = f();
+    let stack = STACK.replace(outer).unwrap_or_default();
+    let spans = stack
+        .into_iter()
+        .next()
+        .map_or_else(Vec::new, |root| root.children);
+    (res, spans)
+}
+
+/// Times the enclosing scope until it is dropped, see [`span!`](crate::span!).
+#[must_use]
+#[derive(Debug)]
+pub struct Guard {
+    start: Option<Instant>,
+}
+
+/// Starts a span that ends when the returned guard is dropped.
+pub fn enter(name: &'static str) -> Guard {
+    let collecting = STACK.with_borrow_mut(|stack| match stack {
+        Some(stack) => {
+            stack.push(Span::new(name));
+            true
+        }
+        None => false,
+    });
+    Guard {
+        start: collecting.then(Instant::now),
+    }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        let Some(start) = self.start else {
+            return;
+        };
+        let time = start.elapsed();
+        STACK.with_borrow_mut(|stack| {
+            let Some(stack) = stack else {
+                return;
+            };
+            // the root is never popped
+            if stack.len() > 1 {
+                let mut span = stack.pop().expect("stack is not empty");
+                span.time = time;
+                span.calls = 1;
+                stack.last_mut().expect("root is left").add(span);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect() {
+        let ((), spans) = collect(|| {
+            crate::span!("outer");
+            for _ in 0..3 {
+                crate::span!("inner");
+            }
+            let answer = crate::span!("expr", 6 * 7);
+            assert_eq!(answer, 42);
+        });
+
+        assert_eq!(spans.len(), 1);
+        let outer = &spans[0];
+        assert_eq!((outer.name, outer.calls), ("outer", 1));
+        let children = outer
+            .children
+            .iter()
+            .map(|s| (s.name, s.calls))
+            .collect::<Vec<_>>();
+        assert_eq!(children, [("inner", 3), ("expr", 1)]);
+        assert!(outer.time >= outer.children.iter().map(|c| c.time).sum());
+
+        // outside of `collect`, spans are not recorded
+        crate::span!("ignored");
+        assert!(enter("ignored").start.is_none());
+    }
+}
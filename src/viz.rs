@@ -0,0 +1,399 @@
+//! Records frames of simulations as images.
+//!
+//! Days create a [`Recorder`] and push frames into it. Nothing is
+//! recorded unless the binary is built with the `viz` feature and run
+//! with `--record <DIR>` or `--animate`. Without the feature, the
+//! recorder has no fields and all of its methods compile to nothing.
+
+use std::{fmt::Display, path::PathBuf};
+
+use crate::{geom::Point2, grid::Grid, grid::Pos};
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// One `.ppm` file per frame.
+    Ppm,
+    /// One `.png` file per frame.
+    #[default]
+    Png,
+    /// A single animated `.gif`.
+    Gif,
+}
+
+#[derive(Debug)]
+pub struct VizError(String);
+
+impl Display for VizError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for VizError {}
+
+/// The frames of a recorder that was created while animating.
+#[derive(Debug, Clone)]
+pub struct Clip {
+    pub name: String,
+    pub frames: Vec<Grid<Rgb>>,
+}
+
+#[cfg(feature = "viz")]
+static OUTPUT: std::sync::OnceLock<(PathBuf, Format)> = std::sync::OnceLock::new();
+
+#[cfg(feature = "viz")]
+static ANIMATE: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+#[cfg(feature = "viz")]
+static CLIPS: std::sync::Mutex<Vec<Clip>> = std::sync::Mutex::new(Vec::new());
+
+/// Makes all recorders created afterwards write their frames to `dir`.
+pub fn record_to(dir: impl Into<PathBuf>, format: Format) -> Result<(), VizError> {
+    #[cfg(feature = "viz")]
+    {
+        OUTPUT
+            .set((dir.into(), format))
+            .map_err(|_| VizError("recording is already configured".to_string()))
+    }
+    #[cfg(not(feature = "viz"))]
+    {
+        let _ = (dir.into(), format);
+        Err(VizError(
+            "recording frames requires the `viz` feature".to_string(),
+        ))
+    }
+}
+
+/// Makes all recorders created afterwards keep their frames in memory,
+/// to be played back once they are dropped, see [`take_clips`].
+pub fn animate() -> Result<(), VizError> {
+    #[cfg(feature = "viz")]
+    {
+        ANIMATE.store(true, std::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
+    #[cfg(not(feature = "viz"))]
+    {
+        Err(VizError(
+            "animating frames requires the `viz` feature".to_string(),
+        ))
+    }
+}
+
+/// Returns the clips of all recorders that were dropped since the last call.
+pub fn take_clips() -> Vec<Clip> {
+    #[cfg(feature = "viz")]
+    {
+        std::mem::take(&mut *CLIPS.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+    #[cfg(not(feature = "viz"))]
+    {
+        Vec::new()
+    }
+}
+
+/// A sequence of frames, written to `<DIR>/<name>/` as numbered
+/// images or to `<DIR>/<name>.gif`.
+#[derive(Debug, Default)]
+pub struct Recorder {
+    #[cfg(feature = "viz")]
+    sink: Option<sink::Sink>,
+    #[cfg(feature = "viz")]
+    clip: Option<Clip>,
+}
+
+impl Recorder {
+    #[cfg(feature = "viz")]
+    pub fn new(name: &str) -> Self {
+        let sink = OUTPUT
+            .get()
+            .map(|(dir, format)| sink::Sink::new(dir.join(name), *format));
+        let clip = ANIMATE
+            .load(std::sync::atomic::Ordering::Relaxed)
+            .then(|| Clip {
+                name: name.to_string(),
+                frames: Vec::new(),
+            });
+        Self { sink, clip }
+    }
+
+    #[cfg(not(feature = "viz"))]
+    #[inline(always)]
+    pub fn new(_name: &str) -> Self {
+        Self {}
+    }
+
+    /// Draws every cell as a square of `scale` by `scale` pixels.
+    #[must_use]
+    #[cfg_attr(not(feature = "viz"), allow(unused_mut))]
+    pub fn scale(mut self, scale: usize) -> Self {
+        #[cfg(feature = "viz")]
+        if let Some(sink) = &mut self.sink {
+            sink.scale = scale.max(1);
+        }
+        #[cfg(not(feature = "viz"))]
+        let _ = scale;
+        self
+    }
+
+    /// Whether frames are written anywhere, e.g. to skip
+    /// collecting state that is only needed for a frame.
+    #[inline(always)]
+    pub fn is_recording(&self) -> bool {
+        #[cfg(feature = "viz")]
+        return self.sink.is_some() || self.clip.is_some();
+        #[cfg(not(feature = "viz"))]
+        false
+    }
+
+    /// Adds a frame of `width` by `height` cells, colored by `pixel`.
+    #[inline]
+    #[cfg_attr(not(feature = "viz"), allow(unused_variables, unused_mut))]
+    pub fn frame<F>(&mut self, width: usize, height: usize, mut pixel: F)
+    where
+        F: FnMut(Pos) -> Rgb,
+    {
+        #[cfg(feature = "viz")]
+        if self.is_recording() {
+            let cells = (0..height)
+                .flat_map(|row| (0..width).map(move |col| (row, col)))
+                .map(&mut pixel)
+                .collect();
+            let image = Grid::new(width, height, cells);
+
+            if let Some(sink) = &mut self.sink {
+                if let Err(e) = sink.write(&image) {
+                    eprintln!("cannot record {}: {e}", sink.path.display());
+                    self.sink = None;
+                }
+            }
+            if let Some(clip) = &mut self.clip {
+                clip.frames.push(image);
+            }
+        }
+    }
+
+    /// Adds a frame with one cell per grid cell.
+    #[inline]
+    pub fn grid<T, F>(&mut self, grid: &Grid<T>, mut color: F)
+    where
+        F: FnMut(&T) -> Rgb,
+    {
+        if self.is_recording() {
+            self.frame(grid.width(), grid.height(), |pos| color(&grid[pos]));
+        }
+    }
+
+    /// Adds a frame showing the area between `min` and `max`, both
+    /// inclusive, with `y` growing downwards. Uncolored cells are black.
+    #[inline]
+    pub fn points<I>(&mut self, min: Point2, max: Point2, points: I)
+    where
+        I: IntoIterator<Item = (Point2, Rgb)>,
+    {
+        if !self.is_recording() {
+            return;
+        }
+
+        let size = max - min;
+        let (width, height) = (size.x() as usize + 1, size.y() as usize + 1);
+        let mut image = Grid::filled(width, height, BLACK);
+
+        for (point, color) in points {
+            if point.within(min, max) {
+                let point = point - min;
+                image[(point.y() as usize, point.x() as usize)] = color;
+            }
+        }
+
+        self.grid(&image, |color| *color);
+    }
+}
+
+#[cfg(feature = "viz")]
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        if let Some(clip) = self.clip.take().filter(|clip| !clip.frames.is_empty()) {
+            CLIPS.lock().unwrap_or_else(|e| e.into_inner()).push(clip);
+        }
+    }
+}
+
+#[cfg(feature = "viz")]
+mod sink {
+    use std::{
+        fs::{self, File},
+        io::{BufWriter, Write},
+        path::PathBuf,
+    };
+
+    use super::{Format, Rgb, VizError};
+    use crate::grid::Grid;
+
+    /// Delay between two frames of a GIF, in hundredths of a second.
+    const GIF_DELAY: u16 = 4;
+
+    pub(super) struct Sink {
+        pub(super) path: PathBuf,
+        pub(super) scale: usize,
+        format: Format,
+        frames: usize,
+        gif: Option<gif::Encoder<BufWriter<File>>>,
+        gif_size: (usize, usize),
+    }
+
+    impl std::fmt::Debug for Sink {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("Sink")
+                .field("path", &self.path)
+                .field("format", &self.format)
+                .field("frames", &self.frames)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl Sink {
+        pub(super) fn new(path: PathBuf, format: Format) -> Self {
+            Self {
+                path,
+                scale: 1,
+                format,
+                frames: 0,
+                gif: None,
+                gif_size: (0, 0),
+            }
+        }
+
+        pub(super) fn write(&mut self, image: &Grid<Rgb>) -> Result<(), VizError> {
+            let (width, height, bytes) = self.render(image);
+
+            match self.format {
+                Format::Ppm => {
+                    let mut file = self.create(&self.frame_path("ppm"))?;
+                    write!(file, "P6\n{width} {height}\n255\n").map_err(io)?;
+                    file.write_all(&bytes).map_err(io)?;
+                    file.flush().map_err(io)?;
+                }
+                Format::Png => {
+                    let file = self.create(&self.frame_path("png"))?;
+                    let mut encoder = png::Encoder::new(file, width as u32, height as u32);
+                    encoder.set_color(png::ColorType::Rgb);
+                    encoder.set_depth(png::BitDepth::Eight);
+                    let mut writer = encoder.write_header().map_err(io)?;
+                    writer.write_image_data(&bytes).map_err(io)?;
+                    writer.finish().map_err(io)?;
+                }
+                Format::Gif => {
+                    let size = (width, height);
+                    let (w, h) = (
+                        u16::try_from(width).map_err(|_| too_large(size))?,
+                        u16::try_from(height).map_err(|_| too_large(size))?,
+                    );
+
+                    if self.gif.is_none() {
+                        let file = self.create(&self.path.with_extension("gif"))?;
+                        let mut encoder = gif::Encoder::new(file, w, h, &[]).map_err(io)?;
+                        encoder.set_repeat(gif::Repeat::Infinite).map_err(io)?;
+                        self.gif = Some(encoder);
+                        self.gif_size = size;
+                    }
+                    if self.gif_size != size {
+                        let (w, h) = self.gif_size;
+                        return Err(VizError(format!(
+                            "frame {} is {width}x{height}, but the first one was {w}x{h}",
+                            self.frames
+                        )));
+                    }
+
+                    let mut frame = gif::Frame::from_rgb_speed(w, h, &bytes, 10);
+                    frame.delay = GIF_DELAY;
+                    let encoder = self.gif.as_mut().expect("the encoder was created above");
+                    encoder.write_frame(&frame).map_err(io)?;
+                }
+            }
+
+            self.frames += 1;
+            Ok(())
+        }
+
+        /// Returns the scaled size and the RGB bytes of a frame.
+        fn render(&self, image: &Grid<Rgb>) -> (usize, usize, Vec<u8>) {
+            let (width, height, scale) = (image.width(), image.height(), self.scale);
+            let mut bytes = Vec::with_capacity(width * height * scale * scale * 3);
+
+            for row in image.rows() {
+                let line = row
+                    .iter()
+                    .flat_map(|color| std::iter::repeat_n(*color, scale).flatten())
+                    .collect::<Vec<_>>();
+                for _ in 0..scale {
+                    bytes.extend_from_slice(&line);
+                }
+            }
+
+            (width * scale, height * scale, bytes)
+        }
+
+        fn frame_path(&self, extension: &str) -> PathBuf {
+            self.path
+                .join(format!("{:05}", self.frames))
+                .with_extension(extension)
+        }
+
+        fn create(&self, path: &PathBuf) -> Result<BufWriter<File>, VizError> {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).map_err(io)?;
+            }
+            File::create(path).map(BufWriter::new).map_err(io)
+        }
+    }
+
+    fn io<E: std::fmt::Display>(e: E) -> VizError {
+        VizError(e.to_string())
+    }
+
+    fn too_large((width, height): (usize, usize)) -> VizError {
+        VizError(format!("frame of {width}x{height} is too large for a GIF"))
+    }
+}
+
+#[cfg(all(test, feature = "viz"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        let dir = std::env::temp_dir().join(format!("aoc-viz-{}", std::process::id()));
+        record_to(&dir, Format::Ppm).unwrap();
+
+        let mut recorder = Recorder::new("2022/day14").scale(2);
+        assert!(recorder.is_recording());
+
+        let grid = Grid::new(2, 1, vec![true, false]);
+        recorder.grid(&grid, |&on| if on { WHITE } else { BLACK });
+        recorder.points(
+            Point2::new([-1, -1]),
+            Point2::new([0, 0]),
+            [(Point2::new([0, -1]), WHITE), (Point2::new([5, 5]), WHITE)],
+        );
+
+        let first = std::fs::read(dir.join("2022/day14/00000.ppm")).unwrap();
+        let header = b"P6\n4 2\n255\n";
+        assert_eq!(&first[..header.len()], header);
+        assert_eq!(
+            &first[header.len()..][..12],
+            [255; 6].iter().chain(&[0; 6]).copied().collect::<Vec<_>>()
+        );
+
+        let second = std::fs::read(dir.join("2022/day14/00001.ppm")).unwrap();
+        assert_eq!(second.len(), header.len() + 4 * 4 * 3);
+        assert_eq!(second[header.len() + 6..][..6], [255; 6]);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}